@@ -1,9 +1,10 @@
 #[cfg(target_os = "linux")]
 use crate::linux::{
     cpu::{Cores, Processor},
+    mem::MemInfo,
     mounts::MountPoints,
-    ps::{ProcessStat, Processes},
-    Linux,
+    ps::{ProcessStat, ProcessTree, Processes},
+    KernelStat, Linux, LoadAvg, SysRoots,
 };
 #[cfg(target_os = "macos")]
 use crate::macos::MacOS;
@@ -11,11 +12,13 @@ use crate::macos::MacOS;
 use crate::windows::Windows;
 
 use crate::{
-    os::{OsImpl, OsImplExt},
+    os::{net::Interfaces, OsImpl, OsImplExt},
     Result,
 };
 use std::boxed::Box;
 use std::env;
+#[cfg(target_os = "linux")]
+use std::path::PathBuf;
 
 /// Main interface that allows for os-agnostic api.
 pub struct Rsys(Box<dyn OsImpl>, Box<dyn OsImplExt>);
@@ -54,6 +57,17 @@ impl Rsys {
         Self::default()
     }
 
+    #[cfg(target_os = "linux")]
+    /// Creates a new instance of Rsys that reads procfs, sysfs and devfs from custom
+    /// root directories instead of `/proc`, `/sys` and `/dev`.
+    pub fn with_roots<P: Into<PathBuf>, S: Into<PathBuf>, D: Into<PathBuf>>(proc: P, sys: S, dev: D) -> Self {
+        let roots = SysRoots::new(proc, sys, dev);
+        Self(
+            Box::new(Linux::with_roots(roots.clone())) as Box<dyn OsImpl>,
+            Box::new(Linux::with_roots(roots)) as Box<dyn OsImplExt>,
+        )
+    }
+
     /// Returns a hostname.
     ///   * **linux**
     ///     * by making a `gethostname` syscall
@@ -94,7 +108,7 @@ impl Rsys {
     ///     * by reading `/proc/cpuinfo`
     ///   * **macos**
     ///     * by calling `sysctl("machdep.cpu.brand_string")`
-    ///   ...
+    ///   * ...
     pub fn cpu(&self) -> Result<String> {
         self.0.cpu()
     }
@@ -104,7 +118,7 @@ impl Rsys {
     ///     * by reading `/proc/cpuinfo`
     ///   * **macos**
     ///     * by calling `sysctl("hw.cpufrequency")`
-    ///   ...
+    ///   * ...
     pub fn cpu_clock(&self) -> Result<f32> {
         self.0.cpu_clock()
     }
@@ -145,7 +159,7 @@ impl Rsys {
     /// Returns free ram memory.
     ///   * **linux**
     ///     * from `SysInfo` structure
-    ///   ...
+    ///   * ...
     pub fn memory_free(&self) -> Result<usize> {
         self.0.memory_free()
     }
@@ -172,16 +186,43 @@ impl Rsys {
         self.0.swap_free()
     }
 
+    /// Returns the load average over 1, 5 and 15 minutes respectively.
+    ///   * **linux**
+    ///     * by reading `/proc/loadavg`
+    ///   * **macos**
+    ///     * by calling `libc::getloadavg`
+    ///   * **windows**
+    ///     * not available, always returns `Error::UnsupportedError`
+    pub fn load_average(&self) -> Result<(f64, f64, f64)> {
+        self.0.load_average()
+    }
+
+    /// Returns network interfaces sorted by index with their addresses.
+    ///   * **linux**
+    ///     * by reading `/sys/class/net` and calling `getifaddrs`
+    ///   * **macos**
+    ///     * not available, always returns `Error::UnsupportedError`
+    ///   * **windows**
+    ///     * not available, always returns `Error::UnsupportedError`
+    pub fn interfaces(&self) -> Result<Interfaces> {
+        self.0.interfaces()
+    }
+
     /// Returns a domain name.
     ///   * **linux**
     ///     * by calling `libc::getdomainname`
     ///   * **windows**
     ///     * by calling win32 api `NetWkstaGetInfo`
-    ///   ...
+    ///   * ...
     pub fn domain_name(&self) -> Result<String> {
         self.0.domain_name()
     }
 
+    #[cfg(target_os = "linux")]
+    /// Returns detailed memory statistics parsed from /proc/meminfo
+    pub fn meminfo(&self) -> Result<MemInfo> {
+        self.1.meminfo()
+    }
     #[cfg(target_os = "linux")]
     /// Returns detailed Process information parsed from /proc/[pid]/stat
     pub fn stat_process(&self, pid: i32) -> Result<ProcessStat> {
@@ -198,6 +239,11 @@ impl Rsys {
         self.1.processes()
     }
     #[cfg(target_os = "linux")]
+    /// Returns a ProcessTree built from a single scan of processes in /proc
+    pub fn process_tree(&self) -> Result<ProcessTree> {
+        self.1.process_tree()
+    }
+    #[cfg(target_os = "linux")]
     /// Returns kernel version of host os.
     pub fn kernel_release(&self) -> Result<String> {
         self.1.kernel_release()
@@ -208,6 +254,16 @@ impl Rsys {
         self.1.mounts()
     }
     #[cfg(target_os = "linux")]
+    /// Returns system wide kernel counters parsed from /proc/stat
+    pub fn kernel_stat(&self) -> Result<KernelStat> {
+        self.1.kernel_stat()
+    }
+    #[cfg(target_os = "linux")]
+    /// Returns load average and scheduler statistics parsed from /proc/loadavg
+    pub fn loadavg(&self) -> Result<LoadAvg> {
+        self.1.loadavg()
+    }
+    #[cfg(target_os = "linux")]
     /// Returns virtual Cores of host cpu
     pub fn cores(&self) -> Result<Cores> {
         self.1.cores()
@@ -7,12 +7,16 @@ pub enum RsysError {
     CommandParseError(String),
     #[error("Failed to read a file at `{0}` - `{1}`")]
     FileReadError(String, String),
+    #[error("Permission denied while accessing `{0}`")]
+    PermissionDenied(String),
     #[error("Failed to acquire local time - `{0}`")]
     TimeError(String),
     #[error("Failed to parse value from input `{0}` - `{1}`")]
     InvalidInputError(String, String),
     #[error("Failed to serialize `{0}` - `{1}`")]
     SerializeError(String, String),
+    #[error("`{0}` is not supported on this platform")]
+    UnsupportedError(String),
     #[cfg(unix)]
     #[error("Syscall failed - `{0}`")]
     NixSyscallError(#[from] nix::Error),
@@ -50,6 +50,7 @@ mod os;
 pub(crate) mod util;
 pub use api::Rsys;
 pub use error::{RsysError as Error, RsysResult as Result};
+pub use os::net;
 
 #[cfg(target_os = "linux")]
 pub use os::linux;
@@ -34,7 +34,8 @@ impl Core {
         Ok(())
     }
 
-    /// Returns the cpu time spent by this core
+    /// Returns the cpu time spent by this core. Use [`cpu_times`](crate::linux::cpu::cpu_times)
+    /// or [`CpuSampler`](crate::linux::cpu::CpuSampler) to read all cores at once.
     pub fn cpu_time(&self) -> Result<Option<CpuTime>> {
         CpuTime::from_stat(&format!("{}", self.id))
     }
@@ -1,10 +1,12 @@
 pub(crate) mod cores;
 pub(crate) mod processor;
 pub(crate) mod time;
+pub(crate) mod usage;
 
 pub use cores::*;
 pub use processor::*;
 pub use time::*;
+pub use usage::*;
 
 use crate::linux::{SysFs, SysPath};
 use crate::{Error, Result};
@@ -52,6 +54,11 @@ pub fn cores() -> Result<Cores> {
     Ok(cores)
 }
 
+/// Returns cpu time of the processor and of every core read at once from /proc/stat
+pub fn cpu_times() -> Result<CpuTimes> {
+    CpuTimes::from_sys()
+}
+
 /// Returns a Processor object containing gathered information
 /// about host machine processor.
 pub fn processor() -> Result<Processor> {
@@ -100,7 +107,7 @@ fn core_ids(path: SysPath) -> Result<Vec<u32>> {
         }
         if let Some(digits) = file_name.split("cpu").last() {
             if let Some(digit) = digits.chars().next() {
-                if !digit.is_digit(10) {
+                if !digit.is_ascii_digit() {
                     continue;
                 }
 
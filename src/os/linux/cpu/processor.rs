@@ -51,7 +51,7 @@ impl Processor {
     }
 
     fn last_line_elem(line: &str) -> &str {
-        line.split(':').last().unwrap_or_default().trim()
+        line.split(':').next_back().unwrap_or_default().trim()
     }
 
     fn bogomips_from(line: &str) -> Result<f32> {
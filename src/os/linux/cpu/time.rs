@@ -1,12 +1,12 @@
 use crate::linux::SysFs;
 use crate::{
     util::{next, skip},
-    Result,
+    Error, Result,
 };
 
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
-use std::str::SplitAsciiWhitespace;
+use std::{collections::BTreeMap, str::SplitAsciiWhitespace};
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
@@ -55,9 +55,13 @@ impl CpuTime {
     }
 
     pub(crate) fn from_stat(id: &str) -> Result<Option<CpuTime>> {
+        CpuTime::_from_stat(&SysFs::Proc.join("stat").read()?, id)
+    }
+
+    fn _from_stat(stat: &str, id: &str) -> Result<Option<CpuTime>> {
         let name = format!("cpu{}", id);
-        for line in SysFs::Proc.join("stat").read()?.lines() {
-            if line.starts_with(&name) {
+        for line in stat.lines() {
+            if line.split_ascii_whitespace().next() == Some(name.as_str()) {
                 return Ok(Some(CpuTime::from_stat_line(line)?));
             }
         }
@@ -87,3 +91,61 @@ impl CpuTime {
         })
     }
 }
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Cpu time of the whole processor and of every core read at once from /proc/stat
+pub struct CpuTimes {
+    /// Time summed over all cores.
+    pub total: CpuTime,
+    /// Time spent by each core mapped by core id.
+    pub cores: BTreeMap<u32, CpuTime>,
+}
+
+impl CpuTimes {
+    pub(crate) fn from_sys() -> Result<CpuTimes> {
+        CpuTimes::from_stat(&SysFs::Proc.join("stat").read()?)
+    }
+
+    pub(crate) fn from_stat(stat: &str) -> Result<CpuTimes> {
+        let mut times = CpuTimes::default();
+        for line in stat.lines() {
+            let name = line.split_ascii_whitespace().next().unwrap_or_default();
+            if name == "cpu" {
+                times.total = CpuTime::from_stat_line(line)?;
+            } else if let Some(id) = name.strip_prefix("cpu") {
+                let id = id
+                    .parse::<u32>()
+                    .map_err(|e| Error::InvalidInputError(line.to_string(), e.to_string()))?;
+                times.cores.insert(id, CpuTime::from_stat_line(line)?);
+            }
+        }
+        Ok(times)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linux::mocks::STAT;
+
+    #[test]
+    fn finds_exact_core_in_stat() {
+        let time = CpuTime::_from_stat(STAT, "1").unwrap().unwrap();
+        assert_eq!(time.user, 10_113);
+        let time = CpuTime::_from_stat(STAT, "10").unwrap().unwrap();
+        assert_eq!(time.user, 11_130);
+        let time = CpuTime::_from_stat(STAT, "").unwrap().unwrap();
+        assert_eq!(time.user, 132_904);
+        assert_eq!(CpuTime::_from_stat(STAT, "12").unwrap(), None);
+    }
+
+    #[test]
+    fn parses_cpu_times() {
+        let times = CpuTimes::from_stat(STAT).unwrap();
+        assert_eq!(times.total.idle, 8_839_611);
+        assert_eq!(times.cores.len(), 12);
+        assert_eq!(times.cores[&0].system, 3_300);
+        assert_eq!(times.cores[&11].softirq, 261);
+    }
+}
@@ -0,0 +1,183 @@
+use crate::linux::cpu::{CpuTime, CpuTimes};
+use crate::Result;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents utilisation of a cpu in percent computed from two CpuTime readings.
+///
+/// Guest time is already accounted in `user` and `nice` by the kernel so it's not part of
+/// the total and the fields `user` through `steal` sum up to 100.
+pub struct CpuUsage {
+    /// Time spent in user mode, including guest time.
+    pub user: f64,
+    /// Time spent in user mode with low priority, including niced guest time.
+    pub nice: f64,
+    pub system: f64,
+    pub idle: f64,
+    pub iowait: f64,
+    pub irq: f64,
+    pub softirq: f64,
+    pub steal: f64,
+    pub guest: f64,
+    pub guest_nice: f64,
+}
+
+impl CpuUsage {
+    /// Computes usage in the interval between `prev` and `cur` readings. If no time elapsed
+    /// between the readings all values are 0.
+    pub fn from_times(prev: &CpuTime, cur: &CpuTime) -> CpuUsage {
+        let user = cur.user.saturating_sub(prev.user);
+        let nice = cur.nice.saturating_sub(prev.nice);
+        let system = cur.system.saturating_sub(prev.system);
+        let idle = cur.idle.saturating_sub(prev.idle);
+        let iowait = cur.iowait.saturating_sub(prev.iowait);
+        let irq = cur.irq.saturating_sub(prev.irq);
+        let softirq = cur.softirq.saturating_sub(prev.softirq);
+        let steal = cur.steal.saturating_sub(prev.steal);
+        let guest = cur.guest.saturating_sub(prev.guest);
+        let guest_nice = cur.guest_nice.saturating_sub(prev.guest_nice);
+
+        let total = user + nice + system + idle + iowait + irq + softirq + steal;
+        if total == 0 {
+            return CpuUsage::default();
+        }
+        let percent = |v: u64| v as f64 * 100. / total as f64;
+
+        CpuUsage {
+            user: percent(user),
+            nice: percent(nice),
+            system: percent(system),
+            idle: percent(idle),
+            iowait: percent(iowait),
+            irq: percent(irq),
+            softirq: percent(softirq),
+            steal: percent(steal),
+            guest: percent(guest),
+            guest_nice: percent(guest_nice),
+        }
+    }
+
+    /// Returns the percentage of time the cpu was busy, that is neither idle nor waiting for I/O
+    pub fn busy(&self) -> f64 {
+        self.user + self.nice + self.system + self.irq + self.softirq + self.steal
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Cpu usage of the whole processor and of every core in a single interval
+pub struct CpuSample {
+    pub total: CpuUsage,
+    /// Usage of each core mapped by core id. Only cores present in both readings are included.
+    pub cores: BTreeMap<u32, CpuUsage>,
+}
+
+impl CpuSample {
+    /// Computes usage in the interval between `prev` and `cur` readings
+    pub fn from_times(prev: &CpuTimes, cur: &CpuTimes) -> CpuSample {
+        CpuSample {
+            total: CpuUsage::from_times(&prev.total, &cur.total),
+            cores: cur
+                .cores
+                .iter()
+                .filter_map(|(id, time)| prev.cores.get(id).map(|prev| (*id, CpuUsage::from_times(prev, time))))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+/// Samples cpu usage of the processor and all cores reading /proc/stat once per sample
+pub struct CpuSampler {
+    prev: CpuTimes,
+}
+
+impl CpuSampler {
+    /// Creates a new sampler taking the initial reading of /proc/stat
+    pub fn new() -> Result<CpuSampler> {
+        Ok(CpuSampler {
+            prev: CpuTimes::from_sys()?,
+        })
+    }
+
+    /// Returns cpu usage since the previous sample or since the sampler was created
+    pub fn sample(&mut self) -> Result<CpuSample> {
+        let cur = CpuTimes::from_sys()?;
+        Ok(self.update(cur))
+    }
+
+    fn update(&mut self, cur: CpuTimes) -> CpuSample {
+        let sample = CpuSample::from_times(&self.prev, &cur);
+        self.prev = cur;
+        sample
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linux::mocks::STAT;
+
+    fn time(user: u64, system: u64, idle: u64, iowait: u64, steal: u64, guest: u64) -> CpuTime {
+        CpuTime {
+            user,
+            system,
+            idle,
+            iowait,
+            steal,
+            guest,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn computes_cpu_usage() {
+        let prev = time(100, 50, 1000, 10, 0, 20);
+        let cur = time(150, 75, 1100, 20, 15, 30);
+        // 50 + 25 + 100 + 10 + 15 = 200 jiffies in total
+        let usage = CpuUsage::from_times(&prev, &cur);
+        assert_eq!(
+            usage,
+            CpuUsage {
+                user: 25.,
+                system: 12.5,
+                idle: 50.,
+                iowait: 5.,
+                steal: 7.5,
+                guest: 5.,
+                ..Default::default()
+            }
+        );
+        assert!((usage.busy() - 45.).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn handles_no_elapsed_time() {
+        let t = time(100, 50, 1000, 10, 0, 0);
+        assert_eq!(CpuUsage::from_times(&t, &t), CpuUsage::default());
+    }
+
+    #[test]
+    fn samples_cpu_usage() {
+        let prev = CpuTimes::from_stat(STAT).unwrap();
+        let mut cur = prev.clone();
+        cur.total.user += 30;
+        cur.total.idle += 90;
+        cur.cores.get_mut(&3).unwrap().system += 10;
+        cur.cores.get_mut(&3).unwrap().idle += 30;
+        cur.cores.remove(&11);
+
+        let mut sampler = CpuSampler { prev };
+        let sample = sampler.update(cur.clone());
+        assert!((sample.total.user - 25.).abs() < f64::EPSILON);
+        assert!((sample.total.idle - 75.).abs() < f64::EPSILON);
+        assert_eq!(sample.cores.len(), 11);
+        assert!((sample.cores[&3].system - 25.).abs() < f64::EPSILON);
+        assert_eq!(sample.cores[&0], CpuUsage::default());
+        assert_eq!(sampler.prev, cur);
+    }
+}
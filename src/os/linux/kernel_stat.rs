@@ -0,0 +1,156 @@
+use crate::linux::cpu::CpuTimes;
+use crate::linux::SysFs;
+use crate::{
+    util::{next, trim_parse_map},
+    Result,
+};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::str::SplitAsciiWhitespace;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Number of softirqs serviced since boot by type
+pub struct SoftIrqs {
+    pub total: u64,
+    pub hi: u64,
+    pub timer: u64,
+    pub net_tx: u64,
+    pub net_rx: u64,
+    pub block: u64,
+    pub irq_poll: u64,
+    pub tasklet: u64,
+    pub sched: u64,
+    pub hrtimer: u64,
+    pub rcu: u64,
+}
+
+impl SoftIrqs {
+    fn from_line(line: &str) -> Result<SoftIrqs> {
+        let counts = line
+            .split_ascii_whitespace()
+            .skip(1)
+            .map(trim_parse_map::<u64>)
+            .collect::<Result<Vec<_>>>()?;
+        let count = |i: usize| counts.get(i).copied().unwrap_or_default();
+
+        Ok(SoftIrqs {
+            total: count(0),
+            hi: count(1),
+            timer: count(2),
+            net_tx: count(3),
+            net_rx: count(4),
+            block: count(5),
+            irq_poll: count(6),
+            tasklet: count(7),
+            sched: count(8),
+            hrtimer: count(9),
+            rcu: count(10),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents system wide kernel counters parsed from /proc/stat
+pub struct KernelStat {
+    /// Time spent by the processor and each of its cores.
+    pub cpu: CpuTimes,
+    /// Total number of context switches across all cpus since boot.
+    pub ctxt: u64,
+    /// Boot time in seconds since the Unix epoch.
+    pub btime: u64,
+    /// Number of forks since boot.
+    pub processes: u64,
+    /// Number of processes in runnable state.
+    pub procs_running: u32,
+    /// Number of processes blocked waiting for I/O to complete.
+    pub procs_blocked: u32,
+    /// Total number of interrupts serviced since boot.
+    pub intr_total: u64,
+    /// Number of interrupts serviced since boot for each numbered interrupt, indexed by irq number.
+    pub intr: Vec<u64>,
+    pub softirq: SoftIrqs,
+}
+
+impl KernelStat {
+    pub(crate) fn from_sys() -> Result<KernelStat> {
+        KernelStat::from_stat(&SysFs::Proc.join("stat").read()?)
+    }
+
+    pub(crate) fn from_stat(stat: &str) -> Result<KernelStat> {
+        let mut kstat = KernelStat {
+            cpu: CpuTimes::from_stat(stat)?,
+            ..Default::default()
+        };
+
+        for line in stat.lines() {
+            let mut elems = line.split_ascii_whitespace();
+
+            macro_rules! _next {
+                ($t:tt) => {
+                    next::<$t, SplitAsciiWhitespace>(&mut elems, &line)?
+                };
+            }
+
+            match elems.next() {
+                Some("ctxt") => kstat.ctxt = _next!(u64),
+                Some("btime") => kstat.btime = _next!(u64),
+                Some("processes") => kstat.processes = _next!(u64),
+                Some("procs_running") => kstat.procs_running = _next!(u32),
+                Some("procs_blocked") => kstat.procs_blocked = _next!(u32),
+                Some("intr") => {
+                    kstat.intr_total = _next!(u64);
+                    kstat.intr = elems.map(trim_parse_map::<u64>).collect::<Result<_>>()?;
+                }
+                Some("softirq") => kstat.softirq = SoftIrqs::from_line(line)?,
+                _ => {}
+            }
+        }
+
+        Ok(kstat)
+    }
+}
+
+/// Returns system wide kernel counters parsed from /proc/stat
+pub fn kernel_stat() -> Result<KernelStat> {
+    KernelStat::from_sys()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linux::mocks::STAT;
+
+    #[test]
+    fn parses_kernel_stat() {
+        let kstat = KernelStat::from_stat(STAT).unwrap();
+        assert_eq!(kstat.cpu, CpuTimes::from_stat(STAT).unwrap());
+        assert_eq!(kstat.ctxt, 48_236_147);
+        assert_eq!(kstat.btime, 1_617_712_123);
+        assert_eq!(kstat.processes, 124_833);
+        assert_eq!(kstat.procs_running, 3);
+        assert_eq!(kstat.procs_blocked, 1);
+        assert_eq!(kstat.intr_total, 22_419_760);
+        assert_eq!(kstat.intr.len(), 48);
+        assert_eq!(kstat.intr[0], 9);
+        assert_eq!(kstat.intr[26], 4578);
+        assert_eq!(
+            kstat.softirq,
+            SoftIrqs {
+                total: 11_251_408,
+                hi: 45,
+                timer: 3_460_531,
+                net_tx: 21,
+                net_rx: 210_345,
+                block: 112,
+                irq_poll: 0,
+                tasklet: 4_321,
+                sched: 4_791_234,
+                hrtimer: 0,
+                rcu: 2_784_799,
+            }
+        );
+    }
+}
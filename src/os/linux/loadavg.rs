@@ -0,0 +1,91 @@
+use crate::linux::SysFs;
+use crate::{
+    util::{next, trim_parse_map},
+    Error, Result,
+};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::str::SplitAsciiWhitespace;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents load average and scheduler statistics parsed from /proc/loadavg
+pub struct LoadAvg {
+    /// Load average over the last minute.
+    pub one: f64,
+    /// Load average over the last 5 minutes.
+    pub five: f64,
+    /// Load average over the last 15 minutes.
+    pub fifteen: f64,
+    /// Number of currently runnable kernel scheduling entities (processes, threads).
+    pub running: u32,
+    /// Number of kernel scheduling entities that currently exist on the system.
+    pub total: u32,
+    /// Pid of the process that was most recently created on the system.
+    pub last_pid: i32,
+}
+
+impl LoadAvg {
+    pub(crate) fn from_sys() -> Result<LoadAvg> {
+        LoadAvg::from_loadavg(&SysFs::Proc.join("loadavg").read()?)
+    }
+
+    pub(crate) fn from_loadavg(loadavg: &str) -> Result<LoadAvg> {
+        let mut elems = loadavg.split_ascii_whitespace();
+
+        macro_rules! _next {
+            ($t:tt) => {
+                next::<$t, SplitAsciiWhitespace>(&mut elems, &loadavg)?
+            };
+        }
+
+        let one = _next!(f64);
+        let five = _next!(f64);
+        let fifteen = _next!(f64);
+        let entities = _next!(String);
+        let (running, total) = entities.split_once('/').ok_or_else(|| {
+            Error::InvalidInputError(entities.clone(), "missing `/` between scheduling entities".to_string())
+        })?;
+
+        Ok(LoadAvg {
+            one,
+            five,
+            fifteen,
+            running: trim_parse_map::<u32>(running)?,
+            total: trim_parse_map::<u32>(total)?,
+            last_pid: _next!(i32),
+        })
+    }
+
+    /// Returns the load average tuple over 1, 5 and 15 minutes respectively.
+    pub fn load_average(&self) -> (f64, f64, f64) {
+        (self.one, self.five, self.fifteen)
+    }
+}
+
+/// Returns load average and scheduler statistics parsed from /proc/loadavg
+pub fn loadavg() -> Result<LoadAvg> {
+    LoadAvg::from_sys()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_loadavg() {
+        assert_eq!(
+            LoadAvg::from_loadavg("0.52 0.58 0.59 2/1017 123456\n").unwrap(),
+            LoadAvg {
+                one: 0.52,
+                five: 0.58,
+                fifteen: 0.59,
+                running: 2,
+                total: 1017,
+                last_pid: 123456,
+            }
+        );
+        assert!(LoadAvg::from_loadavg("0.52 0.58 0.59 1017 123456").is_err());
+    }
+}
@@ -0,0 +1,225 @@
+use crate::linux::SysPath;
+use crate::{util::trim_parse_map, Error, Result};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents memory statistics parsed from /proc/meminfo. All sizes are in Bytes, keys
+/// missing on the running kernel are left as 0.
+pub struct MemInfo {
+    /// Total usable RAM.
+    pub mem_total: u64,
+    /// RAM left completely unused by the system.
+    pub mem_free: u64,
+    /// An estimate of how much memory is available for starting new applications, without swapping.
+    pub mem_available: u64,
+    /// Memory in buffer cache.
+    pub buffers: u64,
+    /// Memory in the pagecache (diskcache and shared memory).
+    pub cached: u64,
+    /// Memory that once was swapped out, is swapped back in but still also is in the swap file.
+    pub swap_cached: u64,
+    /// Memory that has been used more recently and usually not reclaimed unless absolutely necessary.
+    pub active: u64,
+    /// Memory which has been less recently used. It is more eligible to be reclaimed for other purposes.
+    pub inactive: u64,
+    pub active_anon: u64,
+    pub inactive_anon: u64,
+    pub active_file: u64,
+    pub inactive_file: u64,
+    pub unevictable: u64,
+    pub mlocked: u64,
+    /// Total amount of swap space available.
+    pub swap_total: u64,
+    /// Amount of swap space that is currently unused.
+    pub swap_free: u64,
+    /// Memory which is waiting to get written back to the disk.
+    pub dirty: u64,
+    /// Memory which is actively being written back to the disk.
+    pub writeback: u64,
+    /// Non-file backed pages mapped into user-space page tables.
+    pub anon_pages: u64,
+    /// Files which have been mapped into memory, such as libraries.
+    pub mapped: u64,
+    /// Amount of memory consumed in tmpfs filesystems.
+    pub shmem: u64,
+    /// Kernel allocations that the kernel will attempt to reclaim under memory pressure.
+    pub kreclaimable: u64,
+    /// In-kernel data structures cache.
+    pub slab: u64,
+    /// Part of Slab, that might be reclaimed, such as caches.
+    pub sreclaimable: u64,
+    /// Part of Slab, that cannot be reclaimed on memory pressure.
+    pub sunreclaim: u64,
+    pub kernel_stack: u64,
+    pub page_tables: u64,
+    pub nfs_unstable: u64,
+    pub bounce: u64,
+    pub writeback_tmp: u64,
+    /// Total amount of memory currently available to be allocated on the system based on the
+    /// overcommit ratio.
+    pub commit_limit: u64,
+    /// The amount of memory presently allocated on the system.
+    pub committed_as: u64,
+    pub vmalloc_total: u64,
+    pub vmalloc_used: u64,
+    pub vmalloc_chunk: u64,
+    pub percpu: u64,
+    pub hardware_corrupted: u64,
+    /// Non-file backed huge pages mapped into user-space page tables.
+    pub anon_huge_pages: u64,
+    pub shmem_huge_pages: u64,
+    pub shmem_pmd_mapped: u64,
+    pub file_huge_pages: u64,
+    pub file_pmd_mapped: u64,
+    /// The size of the pool of huge pages (count, not Bytes).
+    pub huge_pages_total: u64,
+    /// The number of huge pages in the pool that are not yet allocated (count, not Bytes).
+    pub huge_pages_free: u64,
+    /// The number of huge pages for which a commitment to allocate from the pool has been made,
+    /// but no allocation has yet been made (count, not Bytes).
+    pub huge_pages_rsvd: u64,
+    /// The number of huge pages in the pool above the value in /proc/sys/vm/nr_hugepages
+    /// (count, not Bytes).
+    pub huge_pages_surp: u64,
+    /// The size of huge pages.
+    pub hugepagesize: u64,
+    /// Total amount of memory consumed by huge pages of all sizes.
+    pub hugetlb: u64,
+    pub direct_map_4k: u64,
+    pub direct_map_2m: u64,
+    pub direct_map_1g: u64,
+    /// Keys not known to this crate mapped to their values. Values with a `kB` suffix are
+    /// converted to Bytes.
+    pub other: HashMap<String, u64>,
+}
+
+impl MemInfo {
+    pub(crate) fn from_sys_path(path: &SysPath) -> Result<MemInfo> {
+        MemInfo::from_meminfo(&path.read()?)
+    }
+
+    pub(crate) fn from_meminfo(meminfo: &str) -> Result<MemInfo> {
+        let mut info = MemInfo::default();
+        for line in meminfo.lines() {
+            let mut elems = line.splitn(2, ':');
+            let key = elems.next().unwrap_or_default().trim();
+            if key.is_empty() {
+                continue;
+            }
+            let value = Self::value_from(line, elems.next())?;
+
+            match key {
+                "MemTotal" => info.mem_total = value,
+                "MemFree" => info.mem_free = value,
+                "MemAvailable" => info.mem_available = value,
+                "Buffers" => info.buffers = value,
+                "Cached" => info.cached = value,
+                "SwapCached" => info.swap_cached = value,
+                "Active" => info.active = value,
+                "Inactive" => info.inactive = value,
+                "Active(anon)" => info.active_anon = value,
+                "Inactive(anon)" => info.inactive_anon = value,
+                "Active(file)" => info.active_file = value,
+                "Inactive(file)" => info.inactive_file = value,
+                "Unevictable" => info.unevictable = value,
+                "Mlocked" => info.mlocked = value,
+                "SwapTotal" => info.swap_total = value,
+                "SwapFree" => info.swap_free = value,
+                "Dirty" => info.dirty = value,
+                "Writeback" => info.writeback = value,
+                "AnonPages" => info.anon_pages = value,
+                "Mapped" => info.mapped = value,
+                "Shmem" => info.shmem = value,
+                "KReclaimable" => info.kreclaimable = value,
+                "Slab" => info.slab = value,
+                "SReclaimable" => info.sreclaimable = value,
+                "SUnreclaim" => info.sunreclaim = value,
+                "KernelStack" => info.kernel_stack = value,
+                "PageTables" => info.page_tables = value,
+                "NFS_Unstable" => info.nfs_unstable = value,
+                "Bounce" => info.bounce = value,
+                "WritebackTmp" => info.writeback_tmp = value,
+                "CommitLimit" => info.commit_limit = value,
+                "Committed_AS" => info.committed_as = value,
+                "VmallocTotal" => info.vmalloc_total = value,
+                "VmallocUsed" => info.vmalloc_used = value,
+                "VmallocChunk" => info.vmalloc_chunk = value,
+                "Percpu" => info.percpu = value,
+                "HardwareCorrupted" => info.hardware_corrupted = value,
+                "AnonHugePages" => info.anon_huge_pages = value,
+                "ShmemHugePages" => info.shmem_huge_pages = value,
+                "ShmemPmdMapped" => info.shmem_pmd_mapped = value,
+                "FileHugePages" => info.file_huge_pages = value,
+                "FilePmdMapped" => info.file_pmd_mapped = value,
+                "HugePages_Total" => info.huge_pages_total = value,
+                "HugePages_Free" => info.huge_pages_free = value,
+                "HugePages_Rsvd" => info.huge_pages_rsvd = value,
+                "HugePages_Surp" => info.huge_pages_surp = value,
+                "Hugepagesize" => info.hugepagesize = value,
+                "Hugetlb" => info.hugetlb = value,
+                "DirectMap4k" => info.direct_map_4k = value,
+                "DirectMap2M" => info.direct_map_2m = value,
+                "DirectMap1G" => info.direct_map_1g = value,
+                _ => {
+                    info.other.insert(key.to_string(), value);
+                }
+            }
+        }
+
+        Ok(info)
+    }
+
+    fn value_from(line: &str, value: Option<&str>) -> Result<u64> {
+        let value = value
+            .ok_or_else(|| Error::InvalidInputError(line.to_string(), "missing value in meminfo line".to_string()))?
+            .trim();
+
+        if let Some(kb) = value.strip_suffix("kB") {
+            trim_parse_map::<u64>(kb).map(|v| v * 1024)
+        } else {
+            trim_parse_map::<u64>(value)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linux::mocks::MEMINFO;
+
+    #[test]
+    fn parses_meminfo() {
+        let info = MemInfo::from_meminfo(MEMINFO).unwrap();
+
+        assert_eq!(info.mem_total, 6_147_400 * 1024);
+        assert_eq!(info.mem_free, 4_650_908 * 1024);
+        assert_eq!(info.mem_available, 5_675_064 * 1024);
+        assert_eq!(info.cached, 1_171_968 * 1024);
+        assert_eq!(info.active_anon, 12 * 1024);
+        assert_eq!(info.dirty, 188 * 1024);
+        assert_eq!(info.sreclaimable, 22_940 * 1024);
+        assert_eq!(info.committed_as, 345_356 * 1024);
+        assert_eq!(info.vmalloc_total, 34_359_738_367 * 1024);
+        assert_eq!(info.huge_pages_total, 16);
+        assert_eq!(info.huge_pages_free, 12);
+        assert_eq!(info.hugepagesize, 2048 * 1024);
+        assert_eq!(info.direct_map_1g, 6_291_456 * 1024);
+
+        let mut other = HashMap::new();
+        other.insert("Zswap".to_string(), 0);
+        other.insert("Zswapped".to_string(), 0);
+        other.insert("SecPageTables".to_string(), 0);
+        other.insert("Balloon".to_string(), 4096);
+        assert_eq!(info.other, other);
+    }
+
+    #[test]
+    fn fails_on_invalid_meminfo_value() {
+        assert!(MemInfo::from_meminfo("MemTotal:  abc kB").is_err());
+        assert!(MemInfo::from_meminfo("MemTotal").is_err());
+    }
+}
@@ -1,6 +1,15 @@
-use crate::linux::sysinfo;
+mod meminfo;
+
+pub use meminfo::*;
+
+use crate::linux::{sysinfo, SysFs};
 use crate::Result;
 
+/// Returns detailed memory statistics parsed from /proc/meminfo
+pub fn meminfo() -> Result<MemInfo> {
+    MemInfo::from_sys_path(&SysFs::Proc.join("meminfo"))
+}
+
 /// Returns the total amount of installed RAM in Bytes.
 pub fn memory_total() -> Result<usize> {
     sysinfo().map(|s| s.memory_total() as usize)
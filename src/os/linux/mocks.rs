@@ -29,8 +29,220 @@ cache_alignment : 64
 address sizes   : 43 bits physical, 48 bits virtual
 power management: ts ttp tm hwpstate eff_freq_ro [13] [14]";
 
+/// Content of /proc/stat
+pub(crate) static STAT: &str = "cpu  132904 2103 40527 8839611 6211 0 3118 0 0 0
+cpu0 10000 170 3300 736000 500 0 250 0 0 0
+cpu1 10113 171 3307 736999 501 0 251 0 0 0
+cpu2 10226 172 3314 737998 502 0 252 0 0 0
+cpu3 10339 173 3321 738997 503 0 253 0 0 0
+cpu4 10452 174 3328 739996 504 0 254 0 0 0
+cpu5 10565 175 3335 740995 505 0 255 0 0 0
+cpu6 10678 176 3342 741994 506 0 256 0 0 0
+cpu7 10791 177 3349 742993 507 0 257 0 0 0
+cpu8 10904 178 3356 743992 508 0 258 0 0 0
+cpu9 11017 179 3363 744991 509 0 259 0 0 0
+cpu10 11130 180 3370 745990 510 0 260 0 0 0
+cpu11 11243 181 3377 746989 511 0 261 0 0 0
+intr 22419760 9 0 0 0 0 0 0 0 1 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 4578 0 0 0 0 21 0 305 0 1 0 0 0 0 0 0 0 0 0 0 0 0
+ctxt 48236147
+btime 1617712123
+processes 124833
+procs_running 3
+procs_blocked 1
+softirq 11251408 45 3460531 21 210345 112 0 4321 4791234 0 2784799";
+
+/// Content of /proc/meminfo
+pub(crate) static MEMINFO: &str = "MemTotal:        6147400 kB
+MemFree:         4650908 kB
+MemAvailable:    5675064 kB
+Buffers:           57420 kB
+Cached:          1171968 kB
+SwapCached:            0 kB
+Active:           526112 kB
+Inactive:         879336 kB
+Active(anon):         12 kB
+Inactive(anon):   185096 kB
+Active(file):     526100 kB
+Inactive(file):   694240 kB
+Unevictable:        8884 kB
+Mlocked:            8900 kB
+SwapTotal:             0 kB
+SwapFree:              0 kB
+Zswap:                 0 kB
+Zswapped:              0 kB
+Dirty:               188 kB
+Writeback:             0 kB
+AnonPages:        184996 kB
+Mapped:           143792 kB
+Shmem:              9048 kB
+KReclaimable:      22940 kB
+Slab:              40136 kB
+SReclaimable:      22940 kB
+SUnreclaim:        17196 kB
+KernelStack:        1168 kB
+PageTables:         1756 kB
+SecPageTables:         0 kB
+NFS_Unstable:          0 kB
+Bounce:                0 kB
+WritebackTmp:          0 kB
+CommitLimit:     3073700 kB
+Committed_AS:     345356 kB
+VmallocTotal:   34359738367 kB
+VmallocUsed:       15924 kB
+VmallocChunk:          0 kB
+Percpu:              296 kB
+AnonHugePages:         0 kB
+ShmemHugePages:        0 kB
+ShmemPmdMapped:        0 kB
+FileHugePages:      2048 kB
+FilePmdMapped:         0 kB
+Balloon:               4 kB
+HugePages_Total:      16
+HugePages_Free:       12
+HugePages_Rsvd:        0
+HugePages_Surp:        0
+Hugepagesize:       2048 kB
+Hugetlb:               0 kB
+DirectMap4k:       24576 kB
+DirectMap2M:     2072576 kB
+DirectMap1G:     6291456 kB";
+
 /// Content of /proc/{pid}/stat
 pub(crate) static PROCESS_STAT: &str = "69035 (alacritty) S 1 69035 69035 0 -1 4194304 32394 0 1 0 3977 293 0 0 20 0 26 0 967628 2158927872 45316 18446744073709551615 94056859889664 94056864021361 140722125732880 0 0 0 0 4100 66624 0 0 0 17 6 0 0 0 0 0 94056865348576 94056865641928 94056873410560 140722125737093 140722125737103 140722125737103 140722125737957 0";
+/// Content of /proc/{pid}/status
+pub(crate) static PROCESS_STATUS: &str = "Name:\tcontainerd-shim
+Umask:\t0022
+State:\tS (sleeping)
+Tgid:\t4021
+Ngid:\t0
+Pid:\t4021
+PPid:\t1
+TracerPid:\t0
+Uid:\t1000\t1000\t1000\t1000
+Gid:\t1000\t1000\t1000\t1000
+FDSize:\t64
+Groups:\t4 24 27 1000 
+NStgid:\t4021\t1
+NSpid:\t4021\t1
+NSpgid:\t4021\t1
+NSsid:\t3990\t0
+VmPeak:\t 1242304 kB
+VmSize:\t 1242176 kB
+VmLck:\t       0 kB
+VmPin:\t       0 kB
+VmHWM:\t   20800 kB
+VmRSS:\t   20160 kB
+RssAnon:\t    9024 kB
+RssFile:\t   11136 kB
+RssShmem:\t       0 kB
+VmData:\t   79468 kB
+VmStk:\t     132 kB
+VmExe:\t    3884 kB
+VmLib:\t    2064 kB
+VmPTE:\t     148 kB
+VmSwap:\t     512 kB
+HugetlbPages:\t       0 kB
+CoreDumping:\t0
+THP_enabled:\t1
+Threads:\t11
+SigQ:\t2/63422
+SigPnd:\t0000000000000000
+ShdPnd:\t0000000000000000
+SigBlk:\t0000000000000000
+SigIgn:\t0000000000001000
+SigCgt:\t00000002fffbfeff
+CapInh:\t0000000000000000
+CapPrm:\t0000000000000000
+CapEff:\t0000000000000000
+CapBnd:\t000001ffffffffff
+CapAmb:\t0000000000000000
+NoNewPrivs:\t1
+Seccomp:\t2
+Seccomp_filters:\t1
+Speculation_Store_Bypass:\tthread force mitigated
+Cpus_allowed:\tfff
+Cpus_allowed_list:\t0-11
+Mems_allowed:\t00000001
+Mems_allowed_list:\t0
+voluntary_ctxt_switches:\t1412
+nonvoluntary_ctxt_switches:\t37";
+
+/// Content of /proc/{pid}/maps
+pub(crate) static PROCESS_MAPS: &str =
+    "55811a5d4000-55811a5d6000 r--p 00000000 fe:00 317783                     /usr/bin/head
+55811a5d6000-55811a5dc000 r-xp 00002000 fe:00 317783                     /usr/bin/head
+55811b6e1000-55811b702000 rw-p 00000000 00:00 0                          [heap]
+7f3c2a200000-7f3c2a4e9000 rw-p 00000000 00:00 0 
+7f3c2a4e9000-7f3c2a50f000 r--p 00000000 fe:00 318210                     /usr/lib/x86_64-linux-gnu/libc.so.6 (deleted)
+7f3c2a6f0000-7f3c2a6f4000 rw-s 00000000 00:1a 1042                       /dev/shm/my  segment
+7ffd5b0d6000-7ffd5b0f7000 rw-p 00000000 00:00 0                          [stack]
+7ffd5b1c1000-7ffd5b1c3000 r-xp 00000000 00:00 0                          [vdso]";
+
+/// Content of /proc/{pid}/smaps
+pub(crate) static PROCESS_SMAPS: &str =
+    "55811a5d4000-55811a5d6000 r--p 00000000 fe:00 317783                     /usr/bin/head
+Size:                  8 kB
+KernelPageSize:        4 kB
+MMUPageSize:           4 kB
+Rss:                   8 kB
+Pss:                   4 kB
+Shared_Clean:          8 kB
+Shared_Dirty:          0 kB
+Private_Clean:         0 kB
+Private_Dirty:         0 kB
+Referenced:            8 kB
+Anonymous:             0 kB
+AnonHugePages:         0 kB
+Swap:                  0 kB
+SwapPss:               0 kB
+Locked:                0 kB
+THPeligible:           0
+VmFlags: rd mr mw me 
+55811b6e1000-55811b702000 rw-p 00000000 00:00 0                          [heap]
+Size:                132 kB
+KernelPageSize:        4 kB
+MMUPageSize:           4 kB
+Rss:                  12 kB
+Pss:                  12 kB
+Shared_Clean:          0 kB
+Shared_Dirty:          0 kB
+Private_Clean:         0 kB
+Private_Dirty:        12 kB
+Referenced:           12 kB
+Anonymous:            12 kB
+AnonHugePages:         0 kB
+Swap:                  4 kB
+SwapPss:               4 kB
+Locked:                0 kB
+THPeligible:           0
+VmFlags: rd wr mr mw me ac ";
+
+/// Content of /proc/{pid}/smaps_rollup
+pub(crate) static PROCESS_SMAPS_ROLLUP: &str =
+    "55ff41b70000-7ffe0e2fe000 ---p 00000000 00:00 0                          [rollup]
+Rss:                1296 kB
+Pss:                 484 kB
+Pss_Dirty:           100 kB
+Pss_Anon:            100 kB
+Pss_File:            384 kB
+Pss_Shmem:             0 kB
+Shared_Clean:       1112 kB
+Shared_Dirty:          0 kB
+Private_Clean:        84 kB
+Private_Dirty:       100 kB
+Referenced:         1296 kB
+Anonymous:           100 kB
+KSM:                   0 kB
+LazyFree:              0 kB
+AnonHugePages:         0 kB
+ShmemPmdMapped:        0 kB
+FilePmdMapped:         0 kB
+Shared_Hugetlb:        0 kB
+Private_Hugetlb:       0 kB
+Swap:                  0 kB
+SwapPss:               0 kB
+Locked:                0 kB";
+
 /// Content of /proc/{pid}/stat with whitespace in process name
 pub(crate) static PROCESS_STAT_WHITESPACE_NAME: &str = "1483 (tmux: server) S 1 1483 1483 0 -1 4194368 1521 252 0 0 440 132 0 0 20 0 1 0 8224 12197888 1380 18446744073709551615 93969366433792 93969366876629 140722694246592 0 0 0 0 528386 134433281 0 0 0 17 6 0 0 0 0 0 93969367038768 93969367086920 93969395699712 140722694253341 140722694253346 140722694253346 140722694254570 0";
 
@@ -75,3 +287,151 @@ fusectl /sys/fs/fuse/connections fusectl rw,nosuid,nodev,noexec,relatime 0 0
 /dev/mapper/vgstor-media /mnt/media ext4 rw,relatime 0 0
 tmpfs /run/user/1000 tmpfs rw,nosuid,nodev,relatime,size=1631388k,nr_inodes=407847,mode=700,uid=1000,gid=1000,inode64 0 0
 gvfsd-fuse /run/user/1000/gvfs fuse.gvfsd-fuse rw,nosuid,nodev,relatime,user_id=1000,group_id=1000 0 0";
+
+pub(crate) static NET_DEV: &str = "Inter-|   Receive                                                |  Transmit
+ face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed
+    lo: 35074883    5297    0    0    0     0          0         0 35074883    5297    0    0    0     0       0          0
+  eth0:1927312374 1532411    3   12    1     2          0      8411 84738123  823114    0    4    0     7       1          0
+wlp3s0:       0       0    0    0    0     0          0         0        0       0    0    0    0     0       0          0
+";
+
+pub(crate) static NET_ROUTE: &str = "Iface	Destination	Gateway 	Flags	RefCnt	Use	Metric	Mask		MTU	Window	IRTT                                                       
+wlp3s0	00000000	0100A8C0	0003	0	0	600	00000000	0	0	0                                                                               
+eth0	00000000	FE01A8C0	0003	0	0	100	00000000	0	0	0                                                                               
+eth0	0001A8C0	00000000	0001	0	0	100	00FFFFFF	0	0	0                                                                               
+docker0	000011AC	00000000	0001	0	0	0	0000FFFF	1500	0	0                                                                               
+";
+
+pub(crate) static NET_IPV6_ROUTE: &str = "fd000000000000000000000000000000 40 00000000000000000000000000000000 00 00000000000000000000000000000000 00000100 00000001 00000000 00000001     eth0
+00000000000000000000000000000000 00 00000000000000000000000000000000 00 fe800000000000000000000000000001 00000400 00000001 00000000 00000003     eth0
+00000000000000000000000000000001 80 00000000000000000000000000000000 00 00000000000000000000000000000000 00000000 00000002 00000000 80200001       lo
+00000000000000000000000000000000 00 00000000000000000000000000000000 00 00000000000000000000000000000000 ffffffff 00000001 00000000 00200200       lo
+";
+
+pub(crate) static NET_TCP: &str = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 00000000:0016 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 17890 1 0000000000000000 100 0 0 10 0
+   1: 0100007F:1F90 00000000:0000 0A 00000000:00000005 00:00000000 00000000  1000        0 28391 1 0000000000000000 100 0 0 10 0
+   2: 0201A8C0:C350 2D4C1DCB:01BB 01 00000120:00000000 01:00000016 00000000  1000        0 33012 2 0000000000000000 20 4 30 10 -1
+";
+
+pub(crate) static NET_TCP6: &str = "  sl  local_address                         remote_address                        st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 00000000000000000000000000000000:0016 00000000000000000000000000000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 17892 1 0000000000000000 100 0 0 10 0
+   1: 0000000000000000FFFF00000100007F:1F90 0000000000000000FFFF00000100007F:D431 06 00000000:00000000 03:00000D7A 00000000     0        0 0 3 0000000000000000
+";
+
+pub(crate) static NET_UDP: &str = "   sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode ref pointer drops
+  123: 3500007F:0035 00000000:0000 07 00000000:00000000 00:00000000 00000000   101        0 19870 2 0000000000000000 0
+";
+
+pub(crate) static NET_UNIX: &str = "Num       RefCount Protocol Flags    Type St Inode Path
+0000000000000000: 00000002 00000000 00010000 0001 01 21345 /run/systemd/private
+0000000000000000: 00000002 00000000 00000000 0002 01 12000 @/org/kernel/udev/udevd
+0000000000000000: 00000003 00000000 00000000 0001 03 34567
+";
+
+pub(crate) static NET_SNMP: &str = "Ip: Forwarding DefaultTTL InReceives InHdrErrors InAddrErrors ForwDatagrams InUnknownProtos InDiscards InDelivers OutRequests OutDiscards OutNoRoutes ReasmTimeout ReasmReqds ReasmOKs ReasmFails FragOKs FragFails FragCreates
+Ip: 1 64 28410412 2 17 0 0 4 28410391 25118734 31 12 0 0 0 0 0 0 0
+Icmp: InMsgs InErrors InCsumErrors InDestUnreachs InTimeExcds InParmProbs InSrcQuenchs InRedirects InEchos InEchoReps InTimestamps InTimestampReps InAddrMasks InAddrMaskReps OutMsgs OutErrors OutDestUnreachs OutTimeExcds OutParmProbs OutSrcQuenchs OutRedirects OutEchos OutEchoReps OutTimestamps OutTimestampReps OutAddrMasks OutAddrMaskReps
+Icmp: 1214 3 0 1180 0 0 0 0 34 0 0 0 0 0 1301 0 1267 0 0 0 0 0 34 0 0 0 0
+Tcp: RtoAlgorithm RtoMin RtoMax MaxConn ActiveOpens PassiveOpens AttemptFails EstabResets CurrEstab InSegs OutSegs RetransSegs InErrs OutRsts InCsumErrors
+Tcp: 1 200 120000 -1 184201 3210 4182 2211 37 27851123 26420981 48213 19 9823 0
+Udp: InDatagrams NoPorts InErrors OutDatagrams RcvbufErrors SndbufErrors InCsumErrors IgnoredMulti
+Udp: 541208 1267 88 541892 81 0 7 2810
+UdpLite: InDatagrams NoPorts InErrors OutDatagrams RcvbufErrors SndbufErrors InCsumErrors IgnoredMulti
+UdpLite: 0 0 0 0 0 0 0 0
+";
+
+pub(crate) static NET_SNMP6: &str = "Ip6InReceives                   \t91821
+Ip6InHdrErrors                  \t0
+Ip6InAddrErrors                 \t3
+Ip6InDiscards                   \t1
+Ip6InDelivers                   \t91790
+Ip6OutRequests                  \t90233
+Ip6OutDiscards                  \t0
+Ip6OutNoRoutes                  \t5
+Icmp6InMsgs                     \t412
+Udp6InDatagrams                 \t1804
+Udp6NoPorts                     \t2
+Udp6InErrors                    \t4
+Udp6OutDatagrams                \t1790
+Udp6RcvbufErrors                \t4
+Udp6SndbufErrors                \t0
+";
+
+pub(crate) static NET_NETSTAT: &str = "TcpExt: SyncookiesSent SyncookiesRecv SyncookiesFailed EmbryonicRsts PruneCalled ListenOverflows ListenDrops TCPLostRetransmit TCPFastRetrans TCPSlowStartRetrans TCPTimeouts TCPSynRetrans TCPBacklogDrop TCPOFODrop TCPAbortOnTimeout
+TcpExt: 12 4 1 0 3 321 345 88 10234 512 2841 1193 6 41 77
+IpExt: InNoRoutes InTruncatedPkts InMcastPkts OutMcastPkts InBcastPkts OutBcastPkts InOctets OutOctets
+IpExt: 0 0 1422 388 2910 0 38412094123 4829120338
+";
+
+pub(crate) static NET_SOCKSTAT: &str = "sockets: used 1432
+TCP: inuse 42 orphan 1 tw 17 alloc 58 mem 12
+UDP: inuse 9 mem 4
+UDPLITE: inuse 0
+RAW: inuse 1
+FRAG: inuse 0 memory 0
+";
+
+pub(crate) static DISKSTATS: &str = " 259       0 nvme0n1 1214829 308123 81920154 402811 4938121 1920342 290117320 5120374 0 2841230 5611992 48120 0 98240384 1203 418233 87111
+ 259       1 nvme0n1p1 412 1201 29410 118 2 0 2 0 0 210 118 0 0 0 0 0 0
+ 259       2 nvme0n1p2 1214183 306922 81881688 402633 4938119 1920342 290117318 5120374 0 2841004 5524210 48120 0 98240384 1203 0 0
+   8       0 sda 88123 1923 12312004 912331 31220 12881 8412232 2203112 2 901223 3115443 0 0 0 0
+   8      16 sdb 0 0 0 0 0 0 0 0 0 0 0
+ 254       0 dm-0 1502311 0 80112432 601123 6858412 0 290117318 18830114 0 2911230 19431237 48120 0 98240384 1211
+";
+
+pub(crate) static MOUNTINFO: &str = r"22 1 254:0 / / rw,relatime shared:1 - ext4 /dev/mapper/vgroot-root rw
+23 22 0:21 / /proc rw,nosuid,nodev,noexec,relatime shared:5 - proc proc rw
+24 22 0:22 / /sys rw,nosuid,nodev,noexec,relatime shared:6 - sysfs sys rw
+25 22 0:5 / /dev rw,nosuid,relatime shared:2 - devtmpfs dev rw,size=8144744k,nr_inodes=2036186,mode=755,inode64
+26 25 0:23 / /dev/shm rw,nosuid,nodev shared:3 - tmpfs tmpfs rw,inode64
+27 22 0:24 / /run rw,nosuid,nodev,relatime shared:12 - tmpfs run rw,mode=755,inode64
+31 22 259:1 / /boot rw,relatime shared:29 - vfat /dev/nvme0n1p1 rw,fmask=0022,dmask=0022,codepage=437,iocharset=iso8859-1,shortname=mixed,utf8,errors=remount-ro
+32 22 254:1 / /home rw,relatime shared:31 - ext4 /dev/mapper/vgroot-home rw
+33 22 254:3 / /mnt/media rw,relatime shared:33 - ext4 /dev/mapper/vgstor-media rw
+34 32 254:3 /music /home/user/My\040Music ro,relatime shared:33 - ext4 /dev/mapper/vgstor-media rw
+35 27 0:40 / /run/user/1000 rw,nosuid,nodev,relatime shared:190 - tmpfs tmpfs rw,size=1631388k,nr_inodes=407847,mode=700,uid=1000,gid=1000,inode64
+36 22 0:41 / /mnt/scratch rw,relatime master:12 - tmpfs scratch rw,size=1024k
+37 36 0:42 / /mnt/scratch rw,relatime unbindable - tmpfs scratch2 rw,size=2048k
+38 22 0:43 / /srv/container rw,relatime shared:40 master:7 propagate_from:3 - overlay overlay rw,lowerdir=/var/lib/a,upperdir=/var/lib/b,workdir=/var/lib/c
+";
+
+/// Content of /proc/self/mountinfo with /mnt/a hidden by a later mount on /mnt
+pub(crate) static MOUNTINFO_HIDDEN: &str = r"22 1 254:0 / / rw,relatime shared:1 - ext4 /dev/mapper/vgroot-root rw
+40 22 0:50 / /mnt rw,relatime shared:41 - tmpfs tmpfs rw,size=1024k
+41 40 0:51 / /mnt/a rw,relatime shared:42 - tmpfs tmpfs rw,size=1024k
+42 40 0:52 / /mnt rw,relatime shared:43 - tmpfs tmpfs rw,size=2048k
+";
+
+pub(crate) static FILESYSTEMS: &str = "nodev	sysfs
+nodev	tmpfs
+nodev	bdev
+nodev	proc
+nodev	cgroup
+nodev	cgroup2
+nodev	devtmpfs
+nodev	configfs
+nodev	debugfs
+nodev	tracefs
+nodev	securityfs
+nodev	sockfs
+nodev	bpf
+nodev	pipefs
+nodev	ramfs
+nodev	hugetlbfs
+nodev	devpts
+	ext3
+	ext2
+	ext4
+	vfat
+	fuseblk
+nodev	fuse
+nodev	fusectl
+nodev	efivarfs
+nodev	mqueue
+nodev	pstore
+nodev	autofs
+nodev	nfs
+nodev	nfs4
+	btrfs
+";
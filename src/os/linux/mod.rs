@@ -5,22 +5,29 @@
 pub(crate) mod mocks;
 
 pub mod cpu;
+mod kernel_stat;
+mod loadavg;
 pub mod mem;
 pub mod mounts;
+pub mod net;
 mod os_impl_ext;
 pub mod ps;
+pub mod storage;
 mod sysinfo;
 mod sysproc;
 
 pub use crate::os::unix::{arch, clock_tick, domain_name, hostname, kernel_release};
+pub use kernel_stat::{kernel_stat, KernelStat, SoftIrqs};
+pub use loadavg::{loadavg, LoadAvg};
 pub use sysinfo::{sysinfo, SysInfo};
-pub(crate) use sysproc::{SysFs, SysPath};
+pub use sysproc::{roots, set_roots, SysRoots};
+pub(crate) use sysproc::{with_roots, SysFs, SysPath};
 pub(crate) use {
     mem::{memory_free, memory_total, swap_free, swap_total},
     os_impl_ext::OsImplExt,
 };
 
-use crate::os::OsImpl;
+use crate::os::{net::Interfaces, OsImpl};
 use crate::Result;
 
 /// Returns uptime of host machine in seconds
@@ -29,12 +36,26 @@ pub fn uptime() -> Result<u64> {
 }
 
 #[derive(Default)]
-pub(crate) struct Linux {}
+pub(crate) struct Linux {
+    roots: Option<SysRoots>,
+}
 
 impl Linux {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn with_roots(roots: SysRoots) -> Self {
+        Self { roots: Some(roots) }
+    }
+
+    /// Runs `f` with roots of this instance if there are any, otherwise with module-level roots.
+    pub(crate) fn scoped<T, F: FnOnce() -> Result<T>>(&self, f: F) -> Result<T> {
+        match &self.roots {
+            Some(roots) => with_roots(roots, f),
+            None => f(),
+        }
+    }
 }
 
 impl OsImpl for Linux {
@@ -55,7 +76,7 @@ impl OsImpl for Linux {
     }
 
     fn cpu(&self) -> Result<String> {
-        cpu::model()
+        self.scoped(cpu::model)
     }
 
     fn cpu_clock(&self) -> Result<f32> {
@@ -63,17 +84,17 @@ impl OsImpl for Linux {
             if let Some(clock) = clock {
                 Ok(clock as f32)
             } else {
-                cpu::clock()
+                self.scoped(cpu::clock)
             }
         })
     }
 
     fn cpu_cores(&self) -> Result<u16> {
-        cpu::core_count()
+        self.scoped(cpu::core_count)
     }
 
     fn logical_cores(&self) -> Result<u16> {
-        cpu::logical_cores()
+        self.scoped(cpu::logical_cores)
     }
 
     fn memory_total(&self) -> Result<usize> {
@@ -91,4 +112,12 @@ impl OsImpl for Linux {
     fn swap_free(&self) -> Result<usize> {
         swap_free()
     }
+
+    fn load_average(&self) -> Result<(f64, f64, f64)> {
+        self.scoped(loadavg).map(|l| l.load_average())
+    }
+
+    fn interfaces(&self) -> Result<Interfaces> {
+        self.scoped(net::interfaces)
+    }
 }
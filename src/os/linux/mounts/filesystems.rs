@@ -0,0 +1,75 @@
+use crate::linux::SysFs;
+use crate::{Error, Result};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents a filesystem type supported by the kernel parsed from /proc/filesystems
+pub struct Filesystem {
+    pub name: String,
+    /// Whether the filesystem doesn't need a block device, like proc, tmpfs or network
+    /// filesystems.
+    pub nodev: bool,
+}
+
+impl Filesystem {
+    pub(crate) fn from_line(line: &str) -> Result<Filesystem> {
+        let mut elems = line.split_ascii_whitespace();
+        match (elems.next(), elems.next(), elems.next()) {
+            (Some("nodev"), Some(name), None) => Ok(Filesystem {
+                name: name.to_string(),
+                nodev: true,
+            }),
+            (Some(name), None, None) => Ok(Filesystem {
+                name: name.to_string(),
+                nodev: false,
+            }),
+            _ => Err(Error::InvalidInputError(
+                line.to_string(),
+                "expected filesystem name optionally preceded by `nodev`".to_string(),
+            )),
+        }
+    }
+
+    pub(crate) fn from_filesystems(filesystems: &str) -> Result<Vec<Filesystem>> {
+        filesystems
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(Filesystem::from_line)
+            .collect()
+    }
+}
+
+/// Returns filesystem types supported by the kernel parsed from /proc/filesystems
+pub fn filesystems() -> Result<Vec<Filesystem>> {
+    Filesystem::from_filesystems(&SysFs::Proc.join("filesystems").read()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linux::mocks::FILESYSTEMS;
+
+    #[test]
+    fn parses_filesystems() {
+        let filesystems = Filesystem::from_filesystems(FILESYSTEMS).unwrap();
+        assert_eq!(filesystems.len(), 31);
+        assert_eq!(
+            filesystems[0],
+            Filesystem {
+                name: "sysfs".to_string(),
+                nodev: true,
+            }
+        );
+        assert_eq!(
+            filesystems[19],
+            Filesystem {
+                name: "ext4".to_string(),
+                nodev: false,
+            }
+        );
+        assert!(Filesystem::from_line("nodev a b").is_err());
+    }
+}
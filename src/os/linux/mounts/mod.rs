@@ -1,15 +1,174 @@
+mod filesystems;
+mod mountinfo;
+mod options;
+mod watcher;
+
+pub use filesystems::*;
+pub use mountinfo::*;
+pub use options::*;
+pub use watcher::*;
+
 use crate::linux::SysFs;
 use crate::Result;
 
+use nix::sys::statvfs::statvfs;
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
-use std::convert::AsRef;
+use std::{convert::AsRef, path::Path};
+
+/// Filesystems that don't need a block device but still store data, so they're not considered
+/// pseudo filesystems
+const NODEV_STORAGE: &[&str] = &["nfs", "nfs4", "cifs", "smb3", "ceph", "9p", "zfs", "virtiofs"];
 
-#[derive(Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 pub struct MountPoints(Vec<MountPoint>);
 
-#[derive(Debug, PartialEq)]
+impl MountPoints {
+    pub fn iter(&self) -> std::slice::Iter<'_, MountPoint> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the mountpoint holding `path`, that is the one with the longest path that `path`
+    /// starts with. When multiple filesystems are mounted at the same path the last one hides
+    /// the others and is returned.
+    pub fn find_by_path<P: AsRef<Path>>(&self, path: P) -> Option<&MountPoint> {
+        let path = path.as_ref();
+        self.0
+            .iter()
+            .filter(|mp| path.starts_with(&mp.path))
+            .fold(None, |found: Option<&MountPoint>, mp| match found {
+                Some(found)
+                    if Path::new(&found.path).components().count() > Path::new(&mp.path).components().count() =>
+                {
+                    Some(found)
+                }
+                _ => Some(mp),
+            })
+    }
+
+    /// Returns mountpoints with filesystem type `voltype` like `ext4`
+    pub fn by_type(&self, voltype: &str) -> Vec<&MountPoint> {
+        self.0.iter().filter(|mp| mp.voltype == voltype).collect()
+    }
+
+    /// Returns mountpoints of `volume` like `/dev/sda1`. A volume mounted in multiple places,
+    /// for example with bind mounts, is returned multiple times.
+    pub fn by_volume(&self, volume: &str) -> Vec<&MountPoint> {
+        self.0.iter().filter(|mp| mp.volume == volume).collect()
+    }
+
+    /// Drops pseudo filesystems like proc, sysfs, cgroup or tmpfs which are filesystems that
+    /// /proc/filesystems marks as `nodev`. Network filesystems and others that are `nodev` but
+    /// store data, like nfs, cifs or zfs, are kept.
+    pub fn exclude_pseudo(self) -> Result<MountPoints> {
+        Ok(self.exclude_nodev(&filesystems()?))
+    }
+
+    pub(crate) fn exclude_nodev(self, filesystems: &[Filesystem]) -> MountPoints {
+        MountPoints(
+            self.0
+                .into_iter()
+                .filter(|mp| {
+                    // fuse mounts have types like `fuse.sshfs`
+                    let voltype = mp.voltype.split('.').next().unwrap_or_default();
+                    NODEV_STORAGE.contains(&voltype) || !filesystems.iter().any(|fs| fs.nodev && fs.name == voltype)
+                })
+                .collect(),
+        )
+    }
+
+    /// Fills in `usage` of every mountpoint backed by a filesystem with capacity. Pseudo
+    /// filesystems like proc or sysfs that report no blocks and mountpoints that can't be
+    /// queried, for example because of missing permissions, are left with `None`.
+    ///
+    /// Note that `statvfs` can block for a long time on unresponsive network filesystems and
+    /// that usage is only valid without custom roots, see `MountPoint::usage`.
+    pub fn with_usage(mut self) -> MountPoints {
+        for mp in self.0.iter_mut() {
+            mp.usage = mp.usage().ok().filter(|usage| usage.total > 0);
+        }
+        self
+    }
+}
+
+impl IntoIterator for MountPoints {
+    type Item = MountPoint;
+    type IntoIter = std::vec::IntoIter<MountPoint>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'m> IntoIterator for &'m MountPoints {
+    type Item = &'m MountPoint;
+    type IntoIter = std::slice::Iter<'m, MountPoint>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Capacity and inode usage of a mounted filesystem returned by `statvfs`
+pub struct FsUsage {
+    /// Size of the filesystem in bytes.
+    pub total: u64,
+    /// Free bytes including space reserved for the superuser.
+    pub free: u64,
+    /// Free bytes available to unprivileged users.
+    pub available: u64,
+    /// Preferred I/O block size in bytes.
+    pub block_size: u64,
+    pub inodes_total: u64,
+    pub inodes_free: u64,
+    /// Maximum length of a file name.
+    pub name_max: u64,
+}
+
+impl FsUsage {
+    /// Returns bytes in use
+    pub fn used(&self) -> u64 {
+        self.total.saturating_sub(self.free)
+    }
+
+    /// Returns percentage of space in use out of space available to unprivileged users, the same
+    /// way `df` does. Space reserved for the superuser counts as not available, so a full
+    /// filesystem reports 100 even though root can still write to it.
+    pub fn used_percent(&self) -> f64 {
+        let usable = self.used() + self.available;
+        if usable == 0 {
+            return 0.;
+        }
+        self.used() as f64 / usable as f64 * 100.
+    }
+
+    /// Returns inodes in use
+    pub fn inodes_used(&self) -> u64 {
+        self.inodes_total.saturating_sub(self.inodes_free)
+    }
+
+    /// Returns percentage of inodes in use or 0 if the filesystem doesn't have a fixed number
+    /// of inodes
+    pub fn inodes_used_percent(&self) -> f64 {
+        if self.inodes_total == 0 {
+            return 0.;
+        }
+        self.inodes_used() as f64 / self.inodes_total as f64 * 100.
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 /// Represents a mountpoint parsed from line of /proc/mounts
 pub struct MountPoint {
@@ -18,11 +177,13 @@ pub struct MountPoint {
     pub voltype: String,
     pub mount_mode: MountMode,
     options: Vec<String>,
+    /// Filled in by `MountPoints::with_usage`.
+    pub usage: Option<FsUsage>,
 }
 
 /// Represents an option `ro` or `rw` deciding wether the mountpoint is mounter with read only or
 /// read and write permissions.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 pub enum MountMode {
     ReadWrite,
@@ -53,6 +214,31 @@ impl MountPoint {
         &self.options
     }
 
+    /// Returns options of this mountpoint with flags like `nosuid` or `noexec` interpreted
+    pub fn parsed_options(&self) -> MountOptions {
+        MountOptions::parse(self.options.iter().map(String::as_str))
+    }
+
+    /// Returns current capacity and inode usage of the filesystem mounted at this path.
+    ///
+    /// The path is resolved in the filesystem tree of the current process regardless of
+    /// `SysRoots`, so for mountpoints read with custom roots, like a host's /proc bind-mounted
+    /// into a container, the result describes whatever the current process sees at that path.
+    pub fn usage(&self) -> Result<FsUsage> {
+        let stat = statvfs(self.path.as_str())?;
+        let fragment_size = stat.fragment_size() as u64;
+
+        Ok(FsUsage {
+            total: stat.blocks() as u64 * fragment_size,
+            free: stat.blocks_free() as u64 * fragment_size,
+            available: stat.blocks_available() as u64 * fragment_size,
+            block_size: stat.block_size() as u64,
+            inodes_total: stat.files() as u64,
+            inodes_free: stat.files_free() as u64,
+            name_max: stat.name_max() as u64,
+        })
+    }
+
     pub(crate) fn new(volume: &str, path: &str, voltype: &str, options: &str) -> MountPoint {
         let options: Vec<String> = options.split(',').map(str::to_string).collect();
         let mut mount_mode = MountMode::ReadOnly;
@@ -69,6 +255,7 @@ impl MountPoint {
             voltype: voltype.to_string(),
             mount_mode,
             options,
+            usage: None,
         }
     }
 
@@ -79,13 +266,38 @@ impl MountPoint {
             let path = elems.next()?;
             let voltype = elems.next()?;
             let options = elems.next()?;
-            return Some(Self::new(volume, path, voltype, options));
+            return Some(Self::new(&unescape(volume), &unescape(path), voltype, options));
         }
 
         None
     }
 }
 
+/// Decodes octal escapes like `\040` that the kernel uses for spaces, tabs, newlines and
+/// backslashes in paths of mount tables. Sequences that don't fit in a byte, like `\777`, are
+/// kept as they are.
+pub(crate) fn unescape(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let octal = bytes.get(i + 1..i + 4).filter(|digits| {
+            (b'0'..=b'3').contains(&digits[0]) && digits[1..].iter().all(|d| (b'0'..=b'7').contains(d))
+        });
+        match octal {
+            Some(digits) if bytes[i] == b'\\' => {
+                out.push(digits.iter().fold(0u8, |n, d| n * 8 + (d - b'0')));
+                i += 4;
+            }
+            _ => {
+                out.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).to_string()
+}
+
 /// Returns `MountPoints` read from `/proc/mounts`
 pub fn mounts() -> Result<MountPoints> {
     Ok(_mounts(&SysFs::Proc.join("mounts").read()?))
@@ -104,7 +316,7 @@ fn _mounts(out: &str) -> MountPoints {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::linux::mocks::MOUNTS;
+    use crate::linux::mocks::{FILESYSTEMS, MOUNTS};
 
     #[test]
     fn parses_mountpoints() {
@@ -121,6 +333,7 @@ mod tests {
                     "noexec".to_string(),
                     "relatime".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "sys".to_string(),
@@ -134,6 +347,7 @@ mod tests {
                     "noexec".to_string(),
                     "relatime".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "dev".to_string(),
@@ -149,6 +363,7 @@ mod tests {
                     "mode=755".to_string(),
                     "inode64".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "run".to_string(),
@@ -163,6 +378,7 @@ mod tests {
                     "mode=755".to_string(),
                     "inode64".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "efivarfs".to_string(),
@@ -176,6 +392,7 @@ mod tests {
                     "noexec".to_string(),
                     "relatime".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "/dev/mapper/vgroot-root".to_string(),
@@ -183,6 +400,7 @@ mod tests {
                 voltype: "ext4".to_string(),
                 mount_mode: MountMode::ReadWrite,
                 options: vec!["rw".to_string(), "relatime".to_string()],
+                usage: None,
             },
             MountPoint {
                 volume: "securityfs".to_string(),
@@ -196,6 +414,7 @@ mod tests {
                     "noexec".to_string(),
                     "relatime".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "tmpfs".to_string(),
@@ -208,6 +427,7 @@ mod tests {
                     "nodev".to_string(),
                     "inode64".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "devpts".to_string(),
@@ -223,6 +443,7 @@ mod tests {
                     "mode=620".to_string(),
                     "ptmxmode=000".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "tmpfs".to_string(),
@@ -239,6 +460,7 @@ mod tests {
                     "mode=755".to_string(),
                     "inode64".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "cgroup2".to_string(),
@@ -253,6 +475,7 @@ mod tests {
                     "relatime".to_string(),
                     "nsdelegate".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "cgroup".to_string(),
@@ -268,6 +491,7 @@ mod tests {
                     "xattr".to_string(),
                     "name=systemd".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "pstore".to_string(),
@@ -281,6 +505,7 @@ mod tests {
                     "noexec".to_string(),
                     "relatime".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "none".to_string(),
@@ -295,6 +520,7 @@ mod tests {
                     "relatime".to_string(),
                     "mode=700".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "cgroup".to_string(),
@@ -309,6 +535,7 @@ mod tests {
                     "relatime".to_string(),
                     "rdma".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "cgroup".to_string(),
@@ -324,6 +551,7 @@ mod tests {
                     "cpu".to_string(),
                     "cpuacct".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "cgroup".to_string(),
@@ -338,6 +566,7 @@ mod tests {
                     "relatime".to_string(),
                     "cpuset".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "cgroup".to_string(),
@@ -352,6 +581,7 @@ mod tests {
                     "relatime".to_string(),
                     "pids".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "cgroup".to_string(),
@@ -366,6 +596,7 @@ mod tests {
                     "relatime".to_string(),
                     "freezer".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "cgroup".to_string(),
@@ -380,6 +611,7 @@ mod tests {
                     "relatime".to_string(),
                     "blkio".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "cgroup".to_string(),
@@ -395,6 +627,7 @@ mod tests {
                     "net_cls".to_string(),
                     "net_prio".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "cgroup".to_string(),
@@ -409,6 +642,7 @@ mod tests {
                     "relatime".to_string(),
                     "memory".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "cgroup".to_string(),
@@ -423,6 +657,7 @@ mod tests {
                     "relatime".to_string(),
                     "hugetlb".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "cgroup".to_string(),
@@ -437,6 +672,7 @@ mod tests {
                     "relatime".to_string(),
                     "devices".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "cgroup".to_string(),
@@ -451,6 +687,7 @@ mod tests {
                     "relatime".to_string(),
                     "perf_event".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "systemd-1".to_string(),
@@ -468,6 +705,7 @@ mod tests {
                     "direct".to_string(),
                     "pipe_ino=12809".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "hugetlbfs".to_string(),
@@ -475,6 +713,7 @@ mod tests {
                 voltype: "hugetlbfs".to_string(),
                 mount_mode: MountMode::ReadWrite,
                 options: vec!["rw".to_string(), "relatime".to_string(), "pagesize=2M".to_string()],
+                usage: None,
             },
             MountPoint {
                 volume: "mqueue".to_string(),
@@ -488,6 +727,7 @@ mod tests {
                     "noexec".to_string(),
                     "relatime".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "debugfs".to_string(),
@@ -501,6 +741,7 @@ mod tests {
                     "noexec".to_string(),
                     "relatime".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "tracefs".to_string(),
@@ -514,6 +755,7 @@ mod tests {
                     "noexec".to_string(),
                     "relatime".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "tmpfs".to_string(),
@@ -527,6 +769,7 @@ mod tests {
                     "nr_inodes=409600".to_string(),
                     "inode64".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "configfs".to_string(),
@@ -540,6 +783,7 @@ mod tests {
                     "noexec".to_string(),
                     "relatime".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "fusectl".to_string(),
@@ -553,6 +797,7 @@ mod tests {
                     "noexec".to_string(),
                     "relatime".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "/dev/mapper/vgroot-home".to_string(),
@@ -560,6 +805,7 @@ mod tests {
                 voltype: "ext4".to_string(),
                 mount_mode: MountMode::ReadWrite,
                 options: vec!["rw".to_string(), "relatime".to_string()],
+                usage: None,
             },
             MountPoint {
                 volume: "/dev/mapper/vgroot-var".to_string(),
@@ -567,6 +813,7 @@ mod tests {
                 voltype: "ext4".to_string(),
                 mount_mode: MountMode::ReadWrite,
                 options: vec!["rw".to_string(), "relatime".to_string()],
+                usage: None,
             },
             MountPoint {
                 volume: "/dev/nvme0n1p1".to_string(),
@@ -584,6 +831,7 @@ mod tests {
                     "utf8".to_string(),
                     "errors=remount-ro".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "/dev/mapper/vgstor-rand".to_string(),
@@ -591,6 +839,7 @@ mod tests {
                 voltype: "ext4".to_string(),
                 mount_mode: MountMode::ReadWrite,
                 options: vec!["rw".to_string(), "relatime".to_string()],
+                usage: None,
             },
             MountPoint {
                 volume: "/dev/mapper/vgstor-docs".to_string(),
@@ -598,6 +847,7 @@ mod tests {
                 voltype: "ext4".to_string(),
                 mount_mode: MountMode::ReadWrite,
                 options: vec!["rw".to_string(), "relatime".to_string()],
+                usage: None,
             },
             MountPoint {
                 volume: "/dev/mapper/vgstor-media".to_string(),
@@ -605,6 +855,7 @@ mod tests {
                 voltype: "ext4".to_string(),
                 mount_mode: MountMode::ReadWrite,
                 options: vec!["rw".to_string(), "relatime".to_string()],
+                usage: None,
             },
             MountPoint {
                 volume: "tmpfs".to_string(),
@@ -623,6 +874,7 @@ mod tests {
                     "gid=1000".to_string(),
                     "inode64".to_string(),
                 ],
+                usage: None,
             },
             MountPoint {
                 volume: "gvfsd-fuse".to_string(),
@@ -637,9 +889,147 @@ mod tests {
                     "user_id=1000".to_string(),
                     "group_id=1000".to_string(),
                 ],
+                usage: None,
             },
         ]);
 
         assert_eq!(_mounts(MOUNTS), expected);
     }
+
+    #[test]
+    fn finds_mountpoints() {
+        let mps = _mounts(MOUNTS);
+        assert_eq!(mps.iter().count(), mps.len());
+        let path = |p: &str| mps.find_by_path(p).map(|mp| mp.path.as_str());
+        assert_eq!(path("/etc/fstab"), Some("/"));
+        assert_eq!(path("/home/user/.bashrc"), Some("/home"));
+        assert_eq!(path("/homework"), Some("/"));
+        assert_eq!(path("/run/user/1000/gvfs/share"), Some("/run/user/1000/gvfs"));
+        assert_eq!(
+            path("/sys/fs/cgroup/cpu,cpuacct/tasks"),
+            Some("/sys/fs/cgroup/cpu,cpuacct")
+        );
+        assert_eq!(path("relative"), None);
+
+        let stacked = MountPoints(vec![
+            MountPoint::new("/dev/sda1", "/", "ext4", "rw"),
+            MountPoint::new("/dev/sdb1", "/mnt", "ext4", "rw"),
+            MountPoint::new("tmpfs", "/mnt", "tmpfs", "rw"),
+        ]);
+        assert_eq!(stacked.find_by_path("/mnt/x").unwrap().volume, "tmpfs");
+
+        assert_eq!(mps.by_type("ext4").len(), 6);
+        assert_eq!(mps.by_type("cgroup").len(), 12);
+        assert_eq!(mps.by_volume("/dev/mapper/vgroot-home")[0].path, "/home");
+        assert_eq!(mps.by_volume("tmpfs").len(), 4);
+        assert!(mps.by_volume("/dev/sdz").is_empty());
+
+        let paths: Vec<&str> = (&mps).into_iter().map(|mp| mp.path.as_str()).take(2).collect();
+        assert_eq!(paths, vec!["/proc", "/sys"]);
+    }
+
+    #[test]
+    fn excludes_pseudo_filesystems() {
+        let filesystems = Filesystem::from_filesystems(FILESYSTEMS).unwrap();
+        let mut mps = _mounts(MOUNTS).0;
+        mps.push(MountPoint::new("nas:/export", "/mnt/nas", "nfs4", "rw"));
+        let real = MountPoints(mps).exclude_nodev(&filesystems);
+        assert_eq!(
+            real.into_iter().map(|mp| mp.path).collect::<Vec<_>>(),
+            vec![
+                "/",
+                "/home",
+                "/var",
+                "/boot",
+                "/mnt/rand",
+                "/mnt/docs",
+                "/mnt/media",
+                "/mnt/nas"
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_mount_options() {
+        let mps = _mounts(MOUNTS);
+        let dev = mps.find_by_path("/dev").unwrap().parsed_options();
+        assert!(dev.nosuid);
+        assert!(!dev.noexec);
+        assert_eq!(dev.size(), Some(8_340_217_856));
+        assert_eq!(dev.mode(), Some(0o755));
+
+        // writable mounts allowing both executables and setuid binaries
+        let exposed: Vec<&str> = mps
+            .iter()
+            .filter(|mp| {
+                let opts = mp.parsed_options();
+                !opts.read_only && !opts.noexec && !opts.nosuid
+            })
+            .map(|mp| mp.path.as_str())
+            .collect();
+        assert_eq!(
+            exposed,
+            vec![
+                "/",
+                "/proc/sys/fs/binfmt_misc",
+                "/dev/hugepages",
+                "/home",
+                "/var",
+                "/boot",
+                "/mnt/rand",
+                "/mnt/docs",
+                "/mnt/media"
+            ]
+        );
+    }
+
+    #[test]
+    fn unescapes_paths() {
+        assert_eq!(unescape(r"/mnt/My\040Music"), "/mnt/My Music");
+        assert_eq!(unescape(r"/a\011b\012c\134d"), "/a\tb\nc\\d");
+        assert_eq!(unescape(r"/no\escape\04"), r"/no\escape\04");
+        assert_eq!(unescape(r"/a\777b\400\377"), "/a\\777b\\400\u{fffd}");
+
+        let mp = MountPoint::from_line(r"/dev/sdb1 /media/USB\040DISK vfat rw,relatime 0 0").unwrap();
+        assert_eq!(mp.path, "/media/USB DISK");
+    }
+
+    #[test]
+    fn computes_used_percent() {
+        let usage = FsUsage {
+            total: 1000,
+            free: 300,
+            available: 250,
+            block_size: 4096,
+            inodes_total: 200,
+            inodes_free: 150,
+            name_max: 255,
+        };
+        assert_eq!(usage.used(), 700);
+        assert!((usage.used_percent() - 700. / 950. * 100.).abs() < f64::EPSILON);
+        assert_eq!(usage.inodes_used(), 50);
+        assert!((usage.inodes_used_percent() - 25.).abs() < f64::EPSILON);
+        assert!(FsUsage::default().used_percent().abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn fills_usage_of_real_filesystems() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().to_string_lossy().to_string();
+        let mps = MountPoints(vec![
+            MountPoint::new("/dev/sda1", &path, "ext4", "rw,relatime"),
+            MountPoint::new("proc", "/proc", "proc", "rw,nosuid,nodev,noexec,relatime"),
+            MountPoint::new("/dev/sdz", "/nonexistent/mountpoint", "ext4", "rw"),
+        ])
+        .with_usage();
+
+        let usage = mps.0[0].usage.as_ref().unwrap();
+        assert!(usage.total > 0);
+        assert!(usage.available <= usage.free);
+        assert!(usage.free <= usage.total);
+        assert!(usage.name_max > 0);
+        assert_eq!(mps.0[1].usage, None);
+        assert_eq!(mps.0[2].usage, None);
+        assert!(mps.0[2].usage().is_err());
+    }
 }
@@ -0,0 +1,438 @@
+use crate::linux::{
+    mounts::{unescape, MountOptions},
+    SysFs,
+};
+use crate::{util::trim_parse_map, Error, Result};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    str::SplitAsciiWhitespace,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Propagation of mount and unmount events to and from a mount parsed from the optional fields
+/// of /proc/[pid]/mountinfo
+pub enum Propagation {
+    /// Events are shared with all mounts of this peer group.
+    Shared(u32),
+    /// Events are received from this peer group.
+    Master(u32),
+    /// Events are received from this peer group which is the closest dominant peer group under
+    /// the same root.
+    PropagateFrom(u32),
+    /// Mount can't be bind mounted.
+    Unbindable,
+}
+
+impl Propagation {
+    fn from_field(field: &str) -> Result<Option<Propagation>> {
+        let (tag, value) = match field.split_once(':') {
+            Some((tag, value)) => (tag, Some(value)),
+            None => (field, None),
+        };
+        let group = || trim_parse_map::<u32>(value.unwrap_or_default());
+        Ok(match tag {
+            "shared" => Some(Propagation::Shared(group()?)),
+            "master" => Some(Propagation::Master(group()?)),
+            "propagate_from" => Some(Propagation::PropagateFrom(group()?)),
+            "unbindable" => Some(Propagation::Unbindable),
+            // new fields can be added by future kernels
+            _ => None,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents a mount parsed from a line of /proc/[pid]/mountinfo
+pub struct MountInfo {
+    /// Unique id of the mount, may be reused after unmounting.
+    pub mount_id: u32,
+    /// Id of the parent mount or of self for the root of the mount namespace.
+    pub parent_id: u32,
+    /// Major number of the device the filesystem is on, 0 for filesystems without one.
+    pub major: u32,
+    pub minor: u32,
+    /// Directory of the filesystem that forms the root of this mount. Differs from `/` for bind
+    /// mounts of a subdirectory.
+    pub root: String,
+    pub mount_point: String,
+    /// Options of this mount like `ro` or `noatime`.
+    pub options: Vec<String>,
+    pub propagation: Vec<Propagation>,
+    pub fs_type: String,
+    /// Filesystem specific source like a device path or `none`.
+    pub source: String,
+    /// Options of the filesystem shared by all of its mounts.
+    pub super_options: Vec<String>,
+}
+
+impl MountInfo {
+    pub(crate) fn from_line(line: &str) -> Result<MountInfo> {
+        let (mount, fs) = line.split_once(" - ").ok_or_else(|| {
+            Error::InvalidInputError(line.to_string(), "missing optional fields separator".to_string())
+        })?;
+        let mut elems = mount.split_ascii_whitespace();
+        let mut fs = fs.split_ascii_whitespace();
+
+        let mount_id = trim_parse_map::<u32>(column(&mut elems, line)?)?;
+        let parent_id = trim_parse_map::<u32>(column(&mut elems, line)?)?;
+        let dev = column(&mut elems, line)?;
+        let (major, minor) = dev
+            .split_once(':')
+            .ok_or_else(|| Error::InvalidInputError(dev.to_string(), "expected major:minor".to_string()))?;
+        let root = unescape(column(&mut elems, line)?);
+        let mount_point = unescape(column(&mut elems, line)?);
+        let options = split_options(column(&mut elems, line)?);
+
+        let mut propagation = Vec::new();
+        for field in elems {
+            if let Some(p) = Propagation::from_field(field)? {
+                propagation.push(p);
+            }
+        }
+
+        Ok(MountInfo {
+            mount_id,
+            parent_id,
+            major: trim_parse_map::<u32>(major)?,
+            minor: trim_parse_map::<u32>(minor)?,
+            root,
+            mount_point,
+            options,
+            propagation,
+            fs_type: column(&mut fs, line)?.to_string(),
+            source: unescape(column(&mut fs, line)?),
+            // missing on some old kernels for filesystems without options
+            super_options: fs.next().map(split_options).unwrap_or_default(),
+        })
+    }
+
+    pub(crate) fn from_mountinfo(mountinfo: &str) -> Result<Vec<MountInfo>> {
+        mountinfo
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(MountInfo::from_line)
+            .collect()
+    }
+
+    /// Returns the peer group shared with other mounts if this mount is shared
+    pub fn shared_group(&self) -> Option<u32> {
+        self.propagation.iter().find_map(|p| match p {
+            Propagation::Shared(group) => Some(*group),
+            _ => None,
+        })
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.options.iter().any(|opt| opt == "ro")
+    }
+
+    /// Returns options of this mount combined with options of its superblock. The mount is
+    /// read only if either of them is.
+    pub fn parsed_options(&self) -> MountOptions {
+        MountOptions::parse(self.options.iter().chain(&self.super_options).map(String::as_str))
+    }
+}
+
+fn column<'l>(elems: &mut SplitAsciiWhitespace<'l>, line: &str) -> Result<&'l str> {
+    elems
+        .next()
+        .ok_or_else(|| Error::InvalidInputError(line.to_string(), "missing column".to_string()))
+}
+
+fn split_options(options: &str) -> Vec<String> {
+    options.split(',').map(str::to_string).collect()
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Hierarchy of mounts built from /proc/[pid]/mountinfo
+pub struct MountTree {
+    /// Mounts in the order they appear in mountinfo
+    mounts: Vec<MountInfo>,
+    ids: HashMap<u32, usize>,
+    children: HashMap<u32, Vec<u32>>,
+    roots: Vec<u32>,
+}
+
+impl MountTree {
+    pub fn new(mounts: Vec<MountInfo>) -> MountTree {
+        let ids: HashMap<u32, usize> = mounts.iter().enumerate().map(|(i, m)| (m.mount_id, i)).collect();
+        let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+        let mut roots = Vec::new();
+
+        for mount in mounts.iter() {
+            // parents outside of the mount namespace or chroot are not listed
+            if mount.parent_id != mount.mount_id && ids.contains_key(&mount.parent_id) {
+                children.entry(mount.parent_id).or_default().push(mount.mount_id);
+            } else {
+                roots.push(mount.mount_id);
+            }
+        }
+
+        MountTree {
+            mounts,
+            ids,
+            children,
+            roots,
+        }
+    }
+
+    pub fn get(&self, mount_id: u32) -> Option<&MountInfo> {
+        self.ids.get(&mount_id).map(|i| &self.mounts[*i])
+    }
+
+    pub fn len(&self) -> usize {
+        self.mounts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mounts.is_empty()
+    }
+
+    /// Returns ids of mounts whose parent is not visible, usually just the root filesystem
+    pub fn roots(&self) -> &[u32] {
+        &self.roots
+    }
+
+    /// Returns ids of mounts mounted on top of mount `mount_id` in mount order
+    pub fn children(&self, mount_id: u32) -> &[u32] {
+        self.children.get(&mount_id).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Returns ids of the parent, grandparent and so on of mount `mount_id`
+    pub fn ancestors(&self, mount_id: u32) -> Vec<u32> {
+        let mut ancestors = Vec::new();
+        let mut current = mount_id;
+        while let Some(mount) = self.get(current) {
+            if mount.parent_id == current || self.get(mount.parent_id).is_none() || ancestors.contains(&mount.parent_id)
+            {
+                break;
+            }
+            ancestors.push(mount.parent_id);
+            current = mount.parent_id;
+        }
+        ancestors
+    }
+
+    /// Returns true if another mount was mounted on top of mount `mount_id` at the same path
+    /// hiding it completely
+    pub fn is_overmounted(&self, mount_id: u32) -> bool {
+        self.get(mount_id).is_some_and(|mount| {
+            self.children(mount_id)
+                .iter()
+                .filter_map(|id| self.get(*id))
+                .any(|child| child.mount_point == mount.mount_point)
+        })
+    }
+
+    /// Returns the mount that `path` is currently served by. When multiple mounts are stacked
+    /// on the same mount point the topmost one is returned, mounts hidden below it are never
+    /// returned even if they are mounted deeper in `path`.
+    pub fn find_by_path<P: AsRef<Path>>(&self, path: P) -> Option<&MountInfo> {
+        let path = path.as_ref();
+        let root = self
+            .roots
+            .iter()
+            .filter_map(|id| self.get(*id))
+            .filter(|m| path.starts_with(&m.mount_point))
+            .max_by_key(|m| Path::new(&m.mount_point).components().count())?;
+
+        // walk down from the root one component at a time so that only visible mounts are crossed
+        let mut mount = self.topmost(root);
+        let mut prefix = PathBuf::from(&mount.mount_point);
+        for component in path.strip_prefix(&mount.mount_point).ok()?.components() {
+            prefix.push(component);
+            if let Some(child) = self
+                .children(mount.mount_id)
+                .iter()
+                .filter_map(|id| self.get(*id))
+                .rfind(|child| Path::new(&child.mount_point) == prefix)
+            {
+                mount = self.topmost(child);
+            }
+        }
+        Some(mount)
+    }
+
+    /// Returns the last mount stacked on the mount point of `mount`
+    fn topmost<'t>(&'t self, mut mount: &'t MountInfo) -> &'t MountInfo {
+        let mut seen = vec![mount.mount_id];
+        while let Some(top) = self
+            .children(mount.mount_id)
+            .iter()
+            .filter_map(|id| self.get(*id))
+            .rfind(|child| child.mount_point == mount.mount_point && !seen.contains(&child.mount_id))
+        {
+            seen.push(top.mount_id);
+            mount = top;
+        }
+        mount
+    }
+
+    /// Returns the mount serving `path` and the location of `path` within its filesystem, for
+    /// example `/music/a.flac` for `/home/user/My Music/a.flac` bind mounted from `/music`.
+    pub fn resolve<P: AsRef<Path>>(&self, path: P) -> Option<(&MountInfo, PathBuf)> {
+        let path = path.as_ref();
+        let mount = self.find_by_path(path)?;
+        let relative = path.strip_prefix(&mount.mount_point).ok()?;
+        let fs_path = if relative.as_os_str().is_empty() {
+            PathBuf::from(&mount.root)
+        } else {
+            Path::new(&mount.root).join(relative)
+        };
+        Some((mount, fs_path))
+    }
+
+    /// Returns other mounts of the same filesystem as mount `mount_id`, like bind mounts of it
+    /// or the filesystem it was bind mounted from
+    pub fn same_filesystem(&self, mount_id: u32) -> Vec<&MountInfo> {
+        match self.get(mount_id) {
+            Some(mount) => self
+                .mounts
+                .iter()
+                .filter(|m| m.mount_id != mount_id && m.major == mount.major && m.minor == mount.minor)
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &MountInfo> {
+        self.mounts.iter()
+    }
+}
+
+impl From<Vec<MountInfo>> for MountTree {
+    fn from(mounts: Vec<MountInfo>) -> Self {
+        MountTree::new(mounts)
+    }
+}
+
+/// Returns mounts of the mount namespace of the current process parsed from
+/// /proc/self/mountinfo
+pub fn mountinfo() -> Result<Vec<MountInfo>> {
+    MountInfo::from_mountinfo(&SysFs::Proc.join("self/mountinfo").read()?)
+}
+
+/// Returns hierarchy of mounts of the mount namespace of the current process
+pub fn mount_tree() -> Result<MountTree> {
+    mountinfo().map(MountTree::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linux::mocks::{MOUNTINFO, MOUNTINFO_HIDDEN};
+
+    fn tree() -> MountTree {
+        MountTree::new(MountInfo::from_mountinfo(MOUNTINFO).unwrap())
+    }
+
+    #[test]
+    fn parses_mountinfo() {
+        let mounts = MountInfo::from_mountinfo(MOUNTINFO).unwrap();
+        assert_eq!(mounts.len(), 14);
+        assert_eq!(
+            mounts[9],
+            MountInfo {
+                mount_id: 34,
+                parent_id: 32,
+                major: 254,
+                minor: 3,
+                root: "/music".to_string(),
+                mount_point: "/home/user/My Music".to_string(),
+                options: vec!["ro".to_string(), "relatime".to_string()],
+                propagation: vec![Propagation::Shared(33)],
+                fs_type: "ext4".to_string(),
+                source: "/dev/mapper/vgstor-media".to_string(),
+                super_options: vec!["rw".to_string()],
+            }
+        );
+        assert!(mounts[9].is_read_only());
+        assert!(mounts[9].parsed_options().read_only);
+        assert!(!mounts[0].parsed_options().read_only);
+
+        let opts = mounts[10].parsed_options();
+        assert!(opts.nosuid && opts.nodev && !opts.noexec);
+        assert_eq!(opts.size(), Some(1_670_541_312));
+        assert_eq!(opts.uid(), Some(1000));
+        assert_eq!(mounts[0].parent_id, 1);
+        assert_eq!(mounts[12].propagation, vec![Propagation::Unbindable]);
+        assert_eq!(
+            mounts[13].propagation,
+            vec![
+                Propagation::Shared(40),
+                Propagation::Master(7),
+                Propagation::PropagateFrom(3)
+            ]
+        );
+        assert_eq!(mounts[13].shared_group(), Some(40));
+        assert_eq!(mounts[11].shared_group(), None);
+    }
+
+    #[test]
+    fn fails_on_invalid_line() {
+        assert!(MountInfo::from_line("22 1 254:0 / / rw,relatime shared:1 ext4 /dev/sda rw").is_err());
+        assert!(MountInfo::from_line("22 1 254 / / rw - ext4 /dev/sda rw").is_err());
+        assert!(MountInfo::from_line("22 1 254:0 / / rw shared:x - ext4 /dev/sda rw").is_err());
+        assert!(MountInfo::from_line("22 1 254:0 / / rw - ext4").is_err());
+    }
+
+    #[test]
+    fn builds_mount_hierarchy() {
+        let tree = tree();
+        assert_eq!(tree.roots(), &[22]);
+        assert_eq!(tree.children(22), &[23, 24, 25, 27, 31, 32, 33, 36, 38]);
+        assert_eq!(tree.children(25), &[26]);
+        assert_eq!(tree.ancestors(34), vec![32, 22]);
+        assert!(tree.ancestors(22).is_empty());
+        assert!(tree.is_overmounted(36));
+        assert!(!tree.is_overmounted(37));
+        assert!(!tree.is_overmounted(22));
+    }
+
+    #[test]
+    fn resolves_paths() {
+        let tree = tree();
+        let id = |path: &str| tree.find_by_path(path).map(|m| m.mount_id);
+        assert_eq!(id("/"), Some(22));
+        assert_eq!(id("/etc/fstab"), Some(22));
+        assert_eq!(id("/dev/shm/x"), Some(26));
+        assert_eq!(id("/dev/shmem"), Some(25));
+        assert_eq!(id("/mnt/scratch/file"), Some(37));
+        assert_eq!(id("/home/user/My Music/a.flac"), Some(34));
+        assert_eq!(id("/home/user/.bashrc"), Some(32));
+
+        let (mount, fs_path) = tree.resolve("/home/user/My Music/a.flac").unwrap();
+        assert_eq!(mount.mount_id, 34);
+        assert_eq!(fs_path, PathBuf::from("/music/a.flac"));
+        assert_eq!(tree.resolve("/mnt/media").unwrap().1, PathBuf::from("/"));
+
+        assert_eq!(
+            tree.same_filesystem(34).iter().map(|m| m.mount_id).collect::<Vec<_>>(),
+            vec![33]
+        );
+        assert!(MountTree::default().find_by_path("/").is_none());
+    }
+
+    #[test]
+    fn skips_hidden_mounts() {
+        let tree = MountTree::new(MountInfo::from_mountinfo(MOUNTINFO_HIDDEN).unwrap());
+        assert!(tree.is_overmounted(40));
+        let id = |path: &str| tree.find_by_path(path).map(|m| m.mount_id);
+        assert_eq!(id("/mnt"), Some(42));
+        assert_eq!(id("/mnt/a"), Some(42));
+        assert_eq!(id("/mnt/a/x"), Some(42));
+        assert_eq!(id("/mnt/b"), Some(42));
+        assert_eq!(id("/etc"), Some(22));
+
+        let (mount, fs_path) = tree.resolve("/mnt/a/x").unwrap();
+        assert_eq!(mount.mount_id, 42);
+        assert_eq!(fs_path, PathBuf::from("/a/x"));
+    }
+}
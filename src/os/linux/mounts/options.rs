@@ -0,0 +1,191 @@
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// How access times of files are updated
+pub enum AtimeMode {
+    /// Access time is updated only if it's older than the modification or change time, or
+    /// more than a day old.
+    Relatime,
+    /// Access time is never updated.
+    Noatime,
+    /// Access time is updated on every access. Mount tables don't list any atime option in
+    /// this case.
+    #[default]
+    Strictatime,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Mount options with generic flags interpreted and filesystem specific options like
+/// `size=8144744k` or `mode=755` available as key/value pairs
+pub struct MountOptions {
+    pub read_only: bool,
+    /// Set-user-ID and set-group-ID bits are ignored.
+    pub nosuid: bool,
+    /// Device files can't be accessed.
+    pub nodev: bool,
+    /// Files can't be executed.
+    pub noexec: bool,
+    pub atime: AtimeMode,
+    /// All I/O is done synchronously.
+    pub sync: bool,
+    /// Options in `key=value` form.
+    pub values: BTreeMap<String, String>,
+    /// Other options without a value like `inode64` or `utf8`.
+    pub flags: Vec<String>,
+}
+
+impl MountOptions {
+    /// Parses options from a list like `rw,nosuid,size=8144744k`. If the list contains both
+    /// `ro` and `rw`, as a mountinfo entry combined with options of its superblock might,
+    /// the options are read only.
+    pub fn parse<'o, I: IntoIterator<Item = &'o str>>(options: I) -> MountOptions {
+        let mut opts = MountOptions::default();
+        for option in options {
+            match option {
+                "ro" => opts.read_only = true,
+                "rw" => {}
+                "nosuid" => opts.nosuid = true,
+                "nodev" => opts.nodev = true,
+                "noexec" => opts.noexec = true,
+                "relatime" => opts.atime = AtimeMode::Relatime,
+                "noatime" => opts.atime = AtimeMode::Noatime,
+                "strictatime" => opts.atime = AtimeMode::Strictatime,
+                "sync" => opts.sync = true,
+                "" => {}
+                option => match option.split_once('=') {
+                    Some((key, value)) => {
+                        opts.values.insert(key.to_string(), value.to_string());
+                    }
+                    None => {
+                        if !opts.flags.iter().any(|flag| flag == option) {
+                            opts.flags.push(option.to_string());
+                        }
+                    }
+                },
+            }
+        }
+        opts
+    }
+
+    /// Returns the value of option `key`
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    /// Returns true if flag `flag` like `inode64` is set
+    pub fn has_flag(&self, flag: &str) -> bool {
+        self.flags.iter().any(|f| f == flag)
+    }
+
+    /// Returns the value of option `key` parsed as a number with an optional `k`, `m`, `g`, `t`,
+    /// `p` or `e` binary suffix, like `size=8144744k` or `nr_inodes=1m`. Values relative to
+    /// the amount of memory, like `size=50%`, return None.
+    pub fn get_size(&self, key: &str) -> Option<u64> {
+        parse_size(self.get(key)?)
+    }
+
+    /// Returns the value of option `key` parsed as an octal number, like `mode=755` or
+    /// `fmask=0022`
+    pub fn get_octal(&self, key: &str) -> Option<u32> {
+        u32::from_str_radix(self.get(key)?, 8).ok()
+    }
+
+    /// Returns the size limit in bytes of filesystems like tmpfs
+    pub fn size(&self) -> Option<u64> {
+        self.get_size("size")
+    }
+
+    /// Returns permissions of the root directory of filesystems like tmpfs or devpts
+    pub fn mode(&self) -> Option<u32> {
+        self.get_octal("mode")
+    }
+
+    /// Returns the owner of the root directory or of all files on filesystems without
+    /// ownership like vfat
+    pub fn uid(&self) -> Option<u32> {
+        self.get("uid")?.parse().ok()
+    }
+
+    /// Returns the group of the root directory or of all files on filesystems without
+    /// ownership like vfat
+    pub fn gid(&self) -> Option<u32> {
+        self.get("gid")?.parse().ok()
+    }
+}
+
+fn parse_size(value: &str) -> Option<u64> {
+    let split = value.find(|c: char| !c.is_ascii_digit()).unwrap_or(value.len());
+    let (number, suffix) = value.split_at(split);
+    let shift = match suffix {
+        "" => 0,
+        "k" | "K" => 10,
+        "m" | "M" => 20,
+        "g" | "G" => 30,
+        "t" | "T" => 40,
+        "p" | "P" => 50,
+        "e" | "E" => 60,
+        _ => return None,
+    };
+    number.parse::<u64>().ok()?.checked_mul(1 << shift)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_mount_options() {
+        let opts = MountOptions::parse(
+            "rw,nosuid,nodev,relatime,size=1631388k,nr_inodes=407847,mode=700,uid=1000,gid=1000,inode64".split(','),
+        );
+        assert!(!opts.read_only);
+        assert!(opts.nosuid);
+        assert!(opts.nodev);
+        assert!(!opts.noexec);
+        assert!(!opts.sync);
+        assert_eq!(opts.atime, AtimeMode::Relatime);
+        assert_eq!(opts.size(), Some(1_670_541_312));
+        assert_eq!(opts.get_size("nr_inodes"), Some(407_847));
+        assert_eq!(opts.mode(), Some(0o700));
+        assert_eq!(opts.uid(), Some(1000));
+        assert_eq!(opts.gid(), Some(1000));
+        assert_eq!(opts.flags, vec!["inode64"]);
+        assert!(opts.has_flag("inode64"));
+        assert_eq!(opts.get("mode"), Some("700"));
+        assert_eq!(opts.get("huge"), None);
+
+        let opts = MountOptions::parse(vec![
+            "ro",
+            "noatime",
+            "sync",
+            "fmask=0022",
+            "size=50%",
+            "iocharset=iso8859-1",
+        ]);
+        assert!(opts.read_only);
+        assert!(opts.sync);
+        assert_eq!(opts.atime, AtimeMode::Noatime);
+        assert_eq!(opts.get_octal("fmask"), Some(0o022));
+        assert_eq!(opts.size(), None);
+        assert_eq!(opts.get_octal("iocharset"), None);
+        assert_eq!(opts.mode(), None);
+
+        assert_eq!(MountOptions::parse(vec!["rw"]).atime, AtimeMode::Strictatime);
+        assert!(MountOptions::parse(vec!["ro", "relatime", "rw"]).read_only);
+    }
+
+    #[test]
+    fn parses_sizes() {
+        assert_eq!(parse_size("4096"), Some(4096));
+        assert_eq!(parse_size("4096k"), Some(4_194_304));
+        assert_eq!(parse_size("2M"), Some(2_097_152));
+        assert_eq!(parse_size("1g"), Some(1_073_741_824));
+        assert_eq!(parse_size("16e"), None);
+        assert_eq!(parse_size("10%"), None);
+        assert_eq!(parse_size("k"), None);
+    }
+}
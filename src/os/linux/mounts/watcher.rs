@@ -0,0 +1,228 @@
+use super::{_mounts, MountPoint, MountPoints};
+use crate::linux::{sysproc::file_error, SysFs, SysPath};
+use crate::Result;
+
+use nix::{
+    errno::Errno,
+    poll::{poll, PollFd, PollFlags},
+};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{fs::File, os::unix::io::AsRawFd, time::Duration};
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Change of the mount table reported by `MountWatcher`
+pub enum MountEvent {
+    Added(MountPoint),
+    Removed(MountPoint),
+    /// Same volume is mounted at the same path with different options, for example after
+    /// `mount -o remount,ro`.
+    Remounted {
+        old: MountPoint,
+        new: MountPoint,
+    },
+}
+
+#[derive(Debug)]
+/// Watches the mount table of a mount namespace for changes.
+///
+/// The kernel raises `POLLPRI` on /proc/[pid]/mountinfo whenever a filesystem is mounted,
+/// unmounted or remounted in the mount namespace of that process, so the watcher sleeps in `poll`
+/// instead of rereading the mount table periodically. After each notification /proc/[pid]/mounts
+/// is read and compared with the previous reading.
+pub struct MountWatcher {
+    mountinfo: File,
+    mounts: SysPath,
+    prev: MountPoints,
+}
+
+impl MountWatcher {
+    /// Creates a new watcher of the mount namespace of the current process taking the initial
+    /// reading of /proc/self/mounts
+    pub fn new() -> Result<MountWatcher> {
+        MountWatcher::from_sys_path(&SysFs::Proc.join("self"))
+    }
+
+    /// Creates a new watcher of the mount namespace of process `pid` taking the initial reading
+    /// of /proc/[pid]/mounts. With custom roots pointing at the /proc of a host, pid 1 follows
+    /// the mounts of the host.
+    pub fn for_pid(pid: i32) -> Result<MountWatcher> {
+        MountWatcher::from_sys_path(&SysFs::Proc.join(pid.to_string()))
+    }
+
+    fn from_sys_path(path: &SysPath) -> Result<MountWatcher> {
+        let mountinfo_path = path.extend("mountinfo");
+        let mountinfo = File::open(mountinfo_path.as_path()).map_err(|e| file_error(mountinfo_path.as_path(), e))?;
+        let mounts = path.extend("mounts");
+        let prev = _mounts(&mounts.read()?);
+
+        Ok(MountWatcher {
+            mountinfo,
+            mounts,
+            prev,
+        })
+    }
+
+    /// Returns mountpoints as of the last reading
+    pub fn mounts(&self) -> &MountPoints {
+        &self.prev
+    }
+
+    /// Blocks until the mount table changes or `timeout` passes and returns the changes. Waits
+    /// indefinitely if `timeout` is None. Returns no events on timeout, when interrupted by
+    /// a signal, or when the mount table changed back before it was read.
+    pub fn wait(&mut self, timeout: Option<Duration>) -> Result<Vec<MountEvent>> {
+        let timeout = timeout.map_or(-1, |t| t.as_millis().min(i32::MAX as u128) as i32);
+        let mut fds = [PollFd::new(self.mountinfo.as_raw_fd(), PollFlags::POLLPRI)];
+
+        match poll(&mut fds, timeout) {
+            Ok(0) | Err(nix::Error::Sys(Errno::EINTR)) => return Ok(Vec::new()),
+            Ok(_) => {}
+            Err(e) => return Err(e.into()),
+        }
+        let changed = fds[0]
+            .revents()
+            .is_some_and(|revents| revents.intersects(PollFlags::POLLPRI | PollFlags::POLLERR));
+        if !changed {
+            return Ok(Vec::new());
+        }
+
+        let cur = _mounts(&self.mounts.read()?);
+        let events = diff(&self.prev, &cur);
+        self.prev = cur;
+        Ok(events)
+    }
+
+    /// Returns changes of the mount table since the last call without blocking
+    pub fn check(&mut self) -> Result<Vec<MountEvent>> {
+        self.wait(Some(Duration::from_secs(0)))
+    }
+}
+
+fn same_mount(a: &MountPoint, b: &MountPoint) -> bool {
+    a.volume == b.volume && a.path == b.path && a.voltype == b.voltype
+}
+
+/// Returns events that turn `prev` into `cur`. Filesystems stacked on the same path are matched
+/// in mount order.
+pub(crate) fn diff(prev: &MountPoints, cur: &MountPoints) -> Vec<MountEvent> {
+    let mut removed: Vec<Option<&MountPoint>> = prev.iter().map(Some).collect();
+    let mut added = Vec::new();
+
+    // unchanged mounts first so that a remount of one of several identical mounts is detected
+    for mp in cur.iter() {
+        let unchanged = removed
+            .iter_mut()
+            .find(|old| old.is_some_and(|old| same_mount(old, mp) && old.options() == mp.options()));
+        match unchanged {
+            Some(old) => *old = None,
+            None => added.push(mp),
+        }
+    }
+
+    let mut remounted = Vec::new();
+    let mut new = Vec::new();
+    for mp in added {
+        match removed
+            .iter_mut()
+            .find(|old| old.is_some_and(|old| same_mount(old, mp)))
+        {
+            Some(old) => remounted.push(MountEvent::Remounted {
+                old: old.take().unwrap().clone(),
+                new: mp.clone(),
+            }),
+            None => new.push(MountEvent::Added(mp.clone())),
+        }
+    }
+
+    let mut events: Vec<MountEvent> = removed
+        .into_iter()
+        .flatten()
+        .map(|mp| MountEvent::Removed(mp.clone()))
+        .collect();
+    events.extend(remounted);
+    events.extend(new);
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linux::{mocks::MOUNTS, with_roots, SysRoots};
+
+    #[test]
+    fn diffs_mount_tables() {
+        let prev = _mounts(MOUNTS);
+        assert!(diff(&prev, &prev).is_empty());
+
+        let mut cur: Vec<MountPoint> = prev
+            .iter()
+            .filter(|mp| mp.path != "/mnt/docs")
+            .cloned()
+            .map(|mp| {
+                if mp.path == "/boot" {
+                    MountPoint::new(&mp.volume, &mp.path, &mp.voltype, "ro,relatime")
+                } else {
+                    mp
+                }
+            })
+            .collect();
+        let usb = MountPoint::new(
+            "/dev/sdc1",
+            "/media/USB DISK",
+            "vfat",
+            "rw,nosuid,nodev,relatime,uid=1000",
+        );
+        let nfs = MountPoint::new("nas:/backup", "/mnt/backup", "nfs4", "rw,relatime,vers=4.2");
+        cur.push(usb.clone());
+        cur.push(nfs.clone());
+        let cur = MountPoints(cur);
+
+        let events = diff(&prev, &cur);
+        assert_eq!(events.len(), 4);
+        assert!(matches!(&events[0], MountEvent::Removed(mp) if mp.path == "/mnt/docs"));
+        match &events[1] {
+            MountEvent::Remounted { old, new } => {
+                assert_eq!(old.path, "/boot");
+                assert!(!old.parsed_options().read_only);
+                assert!(new.parsed_options().read_only);
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+        assert_eq!(events[2], MountEvent::Added(usb));
+        assert_eq!(events[3], MountEvent::Added(nfs));
+
+        // one of two identical mounts stacked on the same path was unmounted
+        let tmp = MountPoint::new("tmpfs", "/tmp", "tmpfs", "rw");
+        let stacked = MountPoints(vec![tmp.clone(), tmp.clone()]);
+        let single = MountPoints(vec![tmp.clone()]);
+        assert_eq!(diff(&stacked, &single), vec![MountEvent::Removed(tmp.clone())]);
+        assert_eq!(diff(&single, &stacked), vec![MountEvent::Added(tmp)]);
+    }
+
+    #[test]
+    fn watches_mountinfo() {
+        let mut watcher = MountWatcher::new().unwrap();
+        assert!(!watcher.mounts().is_empty());
+        assert!(watcher.check().is_ok());
+
+        let watcher = MountWatcher::for_pid(std::process::id() as i32).unwrap();
+        assert!(!watcher.mounts().is_empty());
+    }
+
+    #[test]
+    fn watches_with_custom_roots() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        std::fs::create_dir(dir.path().join("1"))?;
+        std::fs::write(dir.path().join("1/mountinfo"), "")?;
+        std::fs::write(dir.path().join("1/mounts"), MOUNTS)?;
+
+        let roots = SysRoots::new(dir.path(), "/sys", "/dev");
+        let mut watcher = with_roots(&roots, || MountWatcher::for_pid(1)).unwrap();
+        assert_eq!(watcher.mounts(), &_mounts(MOUNTS));
+        assert!(watcher.check().unwrap().is_empty());
+        assert!(with_roots(&roots, MountWatcher::new).is_err());
+        dir.close()
+    }
+}
@@ -0,0 +1,315 @@
+use crate::linux::{net::InterfaceStats, roots, SysFs, SysPath, SysRoots};
+pub use crate::os::net::{Duplex, Interface, InterfaceAddress, InterfaceType, Interfaces, OperState};
+use crate::{Error, Result};
+
+use nix::{ifaddrs::getifaddrs, sys::socket::SockAddr};
+use std::{collections::HashMap, net::IpAddr};
+
+/// ARPHRD_ETHER from linux/if_arp.h
+const ARPHRD_ETHER: u16 = 1;
+/// ARPHRD_LOOPBACK from linux/if_arp.h
+const ARPHRD_LOOPBACK: u16 = 772;
+
+impl Interface {
+    pub(crate) fn from_sys_path(path: &SysPath) -> Result<Interface> {
+        let name = path
+            .as_path()
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let mac = path.extend("address").read()?.trim().to_string();
+        let flags = path.extend("flags").read()?;
+
+        Ok(Interface {
+            name,
+            index: path.extend("ifindex").read_as::<u32>()?,
+            kind: interface_type(path)?,
+            mac: if mac.is_empty() { None } else { Some(mac) },
+            mtu: path.extend("mtu").read_as::<u32>()?,
+            operstate: OperState::from(path.extend("operstate").read()?.as_str()),
+            carrier: path.extend("carrier").read_as::<u8>().ok().map(|carrier| carrier == 1),
+            speed: path
+                .extend("speed")
+                .read_as::<i64>()
+                .ok()
+                .filter(|speed| *speed > 0)
+                .map(|speed| speed as u32),
+            duplex: match path.extend("duplex").read().ok().as_deref().map(str::trim) {
+                Some("full") => Some(Duplex::Full),
+                Some("half") => Some(Duplex::Half),
+                _ => None,
+            },
+            flags: u32::from_str_radix(flags.trim().trim_start_matches("0x"), 16)
+                .map_err(|e| Error::InvalidInputError(flags.clone(), e.to_string()))?,
+            addresses: Vec::new(),
+        })
+    }
+
+    /// Returns traffic counters of this interface read from /sys/class/net/[iface]/statistics
+    pub fn stats(&self) -> Result<InterfaceStats> {
+        InterfaceStats::from_sys_path(&SysFs::Sys.join("class/net").join(&self.name))
+    }
+
+    /// Returns true if the interface is administratively up
+    pub fn is_up(&self) -> bool {
+        self.flags & libc::IFF_UP as u32 != 0
+    }
+
+    pub fn is_loopback(&self) -> bool {
+        self.flags & libc::IFF_LOOPBACK as u32 != 0
+    }
+
+    pub fn is_promiscuous(&self) -> bool {
+        self.flags & libc::IFF_PROMISC as u32 != 0
+    }
+
+    pub fn is_multicast(&self) -> bool {
+        self.flags & libc::IFF_MULTICAST as u32 != 0
+    }
+}
+
+fn interface_type(path: &SysPath) -> Result<InterfaceType> {
+    let kind = path.extend("type").read_as::<u16>()?;
+    let devtype = path
+        .extend("uevent")
+        .read()
+        .ok()
+        .and_then(|uevent| {
+            uevent
+                .lines()
+                .find_map(|line| line.strip_prefix("DEVTYPE=").map(str::to_string))
+        })
+        .unwrap_or_default();
+
+    if kind == ARPHRD_LOOPBACK {
+        return Ok(InterfaceType::Loopback);
+    }
+    if devtype == "bridge" || path.extend("bridge").as_path().exists() {
+        return Ok(InterfaceType::Bridge);
+    }
+    if path.extend("tun_flags").as_path().exists() {
+        return Ok(InterfaceType::Tun);
+    }
+    if kind == ARPHRD_ETHER {
+        // veth pairs are virtual devices without any DEVTYPE whose link points to the peer
+        let virtual_dev = devtype.is_empty() && !path.extend("device").as_path().exists();
+        let ifindex = path.extend("ifindex").read_as::<u32>()?;
+        let iflink = path.extend("iflink").read_as::<u32>().unwrap_or(ifindex);
+        if virtual_dev && iflink != ifindex {
+            return Ok(InterfaceType::Veth);
+        }
+        return Ok(InterfaceType::Ether);
+    }
+
+    Ok(InterfaceType::Other(kind))
+}
+
+fn ip_addr(addr: Option<SockAddr>) -> Option<IpAddr> {
+    match addr {
+        Some(SockAddr::Inet(addr)) => Some(addr.ip().to_std()),
+        _ => None,
+    }
+}
+
+/// Returns addresses of all interfaces mapped by interface name. `getifaddrs` always reports
+/// the network namespace of the current process, so nothing is returned when sysfs is read from
+/// a custom root as the addresses could belong to unrelated interfaces with the same name.
+fn addresses() -> Result<HashMap<String, Vec<InterfaceAddress>>> {
+    let mut addresses: HashMap<String, Vec<InterfaceAddress>> = HashMap::new();
+    if roots().sys != SysRoots::default().sys {
+        return Ok(addresses);
+    }
+    for ifaddr in getifaddrs()? {
+        if let Some(address) = ip_addr(ifaddr.address) {
+            addresses
+                .entry(ifaddr.interface_name)
+                .or_default()
+                .push(InterfaceAddress {
+                    address,
+                    netmask: ip_addr(ifaddr.netmask),
+                    broadcast: ip_addr(ifaddr.broadcast),
+                });
+        }
+    }
+    Ok(addresses)
+}
+
+pub(crate) fn interfaces_from(
+    path: &SysPath,
+    mut addresses: HashMap<String, Vec<InterfaceAddress>>,
+) -> Result<Interfaces> {
+    let mut interfaces = Vec::new();
+    for entry in path.read_dir()?.flatten() {
+        let mut iface = match Interface::from_sys_path(&SysFs::Custom(entry.path()).into_syspath()) {
+            Ok(iface) => iface,
+            // interface removed in the meantime
+            Err(Error::FileReadError(_, _)) => continue,
+            Err(e) => return Err(e),
+        };
+        iface.addresses = addresses.remove(&iface.name).unwrap_or_default();
+        interfaces.push(iface);
+    }
+    interfaces.sort_by_key(|iface| iface.index);
+    Ok(interfaces)
+}
+
+/// Returns all network interfaces read from /sys/class/net sorted by index with their addresses
+/// returned by `getifaddrs`. Addresses are left empty if sysfs is read from a custom root.
+pub fn interfaces() -> Result<Interfaces> {
+    interfaces_from(&SysFs::Sys.join("class/net"), addresses()?)
+}
+
+/// Returns a network interface with `name` read from /sys/class/net/[name]
+pub fn interface(name: &str) -> Result<Interface> {
+    let mut iface = Interface::from_sys_path(&SysFs::Sys.join("class/net").join(name))?;
+    iface.addresses = addresses()?.remove(name).unwrap_or_default();
+    Ok(iface)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs, io, net::Ipv4Addr, path::Path};
+
+    fn write_iface(root: &Path, name: &str, files: &[(&str, &str)]) -> io::Result<()> {
+        let dir = root.join(name);
+        fs::create_dir_all(&dir)?;
+        for (file, content) in files {
+            fs::write(dir.join(file), content)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn reads_interfaces() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let common = |index: &'static str, kind: &'static str, flags: &'static str| {
+            vec![
+                ("ifindex", index),
+                ("iflink", index),
+                ("type", kind),
+                ("flags", flags),
+                ("mtu", "1500\n"),
+            ]
+        };
+
+        let mut eth0 = common("2\n", "1\n", "0x1003\n");
+        eth0.extend(vec![
+            ("address", "02:fc:00:00:00:01\n"),
+            ("operstate", "up\n"),
+            ("carrier", "1\n"),
+            ("speed", "1000\n"),
+            ("duplex", "full\n"),
+            ("device", ""),
+        ]);
+        write_iface(dir.path(), "eth0", &eth0)?;
+
+        let mut lo = common("1\n", "772\n", "0x9\n");
+        lo.extend(vec![
+            ("address", "00:00:00:00:00:00\n"),
+            ("operstate", "unknown\n"),
+            ("carrier", "1\n"),
+        ]);
+        write_iface(dir.path(), "lo", &lo)?;
+
+        let mut br0 = common("3\n", "1\n", "0x1003\n");
+        br0.extend(vec![
+            ("address", "aa:bb:cc:dd:ee:ff\n"),
+            ("operstate", "down\n"),
+            ("uevent", "DEVTYPE=bridge\nINTERFACE=br0\nIFINDEX=3\n"),
+        ]);
+        write_iface(dir.path(), "br0", &br0)?;
+
+        write_iface(
+            dir.path(),
+            "veth1",
+            &[
+                ("ifindex", "4\n"),
+                ("iflink", "5\n"),
+                ("type", "1\n"),
+                ("flags", "0x1103\n"),
+                ("mtu", "1500\n"),
+                ("address", "5e:11:22:33:44:55\n"),
+                ("operstate", "up\n"),
+                ("speed", "-1\n"),
+            ],
+        )?;
+
+        let mut tun0 = common("6\n", "65534\n", "0x1091\n");
+        tun0.extend(vec![
+            ("address", "\n"),
+            ("operstate", "unknown\n"),
+            ("tun_flags", "0x1001\n"),
+        ]);
+        write_iface(dir.path(), "tun0", &tun0)?;
+
+        let mut addresses = HashMap::new();
+        addresses.insert(
+            "eth0".to_string(),
+            vec![InterfaceAddress {
+                address: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)),
+                netmask: Some(IpAddr::V4(Ipv4Addr::new(255, 255, 255, 0))),
+                broadcast: Some(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 255))),
+            }],
+        );
+
+        let ifaces = interfaces_from(&SysFs::Custom(dir.path().to_owned()).into_syspath(), addresses).unwrap();
+        let names: Vec<_> = ifaces.iter().map(|i| (i.name.as_str(), i.kind)).collect();
+        assert_eq!(
+            names,
+            vec![
+                ("lo", InterfaceType::Loopback),
+                ("eth0", InterfaceType::Ether),
+                ("br0", InterfaceType::Bridge),
+                ("veth1", InterfaceType::Veth),
+                ("tun0", InterfaceType::Tun),
+            ]
+        );
+
+        let eth0 = &ifaces[1];
+        assert_eq!(eth0.mac.as_deref(), Some("02:fc:00:00:00:01"));
+        assert_eq!(eth0.mtu, 1500);
+        assert_eq!(eth0.operstate, OperState::Up);
+        assert_eq!(eth0.carrier, Some(true));
+        assert_eq!(eth0.speed, Some(1000));
+        assert_eq!(eth0.duplex, Some(Duplex::Full));
+        assert!(eth0.is_up() && eth0.is_multicast() && !eth0.is_loopback());
+        assert_eq!(eth0.addresses.len(), 1);
+        assert_eq!(eth0.addresses[0].prefix_len(), Some(24));
+
+        assert!(ifaces[0].is_loopback());
+        assert_eq!(ifaces[2].carrier, None);
+        assert!(ifaces[3].is_promiscuous());
+        assert_eq!(ifaces[3].speed, None);
+        assert_eq!(ifaces[4].mac, None);
+        Ok(())
+    }
+
+    #[test]
+    fn skips_addresses_with_custom_roots() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        write_iface(
+            &dir.path().join("class/net"),
+            "lo",
+            &[
+                ("ifindex", "1\n"),
+                ("type", "772\n"),
+                ("flags", "0x9\n"),
+                ("mtu", "65536\n"),
+                ("address", "00:00:00:00:00:00\n"),
+                ("operstate", "unknown\n"),
+            ],
+        )?;
+
+        let roots = SysRoots::new("/proc", dir.path(), "/dev");
+        let ifaces = crate::linux::with_roots(&roots, interfaces).unwrap();
+        assert_eq!(ifaces.len(), 1);
+        assert!(ifaces[0].is_loopback());
+        assert!(ifaces[0].addresses.is_empty());
+        assert!(crate::linux::with_roots(&roots, || interface("lo"))
+            .unwrap()
+            .addresses
+            .is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,22 @@
+//! Network interfaces, routes, sockets and statistics
+
+/// Parses a hex number of type `$t` returning InvalidInputError on failure
+macro_rules! hex {
+    ($t:ty, $s:expr) => {
+        <$t>::from_str_radix($s, 16).map_err(|e| crate::Error::InvalidInputError($s.to_string(), e.to_string()))
+    };
+}
+
+mod interface;
+mod route;
+mod sampler;
+mod snmp;
+mod socket;
+mod stats;
+
+pub use interface::*;
+pub use route::*;
+pub use sampler::*;
+pub use snmp::*;
+pub use socket::*;
+pub use stats::*;
@@ -0,0 +1,224 @@
+use crate::linux::SysFs;
+use crate::{util::trim_parse_map, Error, Result};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// Route is usable
+pub const RTF_UP: u32 = 0x0001;
+/// Destination is reached through a gateway
+pub const RTF_GATEWAY: u32 = 0x0002;
+/// Route to a single host
+pub const RTF_HOST: u32 = 0x0004;
+/// Packets to the destination are rejected
+pub const RTF_REJECT: u32 = 0x0200;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents an entry of the kernel routing table parsed from /proc/net/route or
+/// /proc/net/ipv6_route
+pub struct Route {
+    pub destination: IpAddr,
+    pub prefix_len: u8,
+    /// Next hop or None if the destination is directly reachable.
+    pub gateway: Option<IpAddr>,
+    pub interface: String,
+    pub metric: u32,
+    /// Route flags, `RTF_*` constants from linux/route.h and linux/ipv6_route.h
+    pub flags: u32,
+}
+
+impl Route {
+    pub(crate) fn from_route_line(line: &str) -> Result<Route> {
+        let elems: Vec<&str> = line.split_ascii_whitespace().collect();
+        if elems.len() < 8 {
+            return Err(Error::InvalidInputError(
+                line.to_string(),
+                "expected at least 8 columns".to_string(),
+            ));
+        }
+
+        let gateway = parse_ipv4(elems[2])?;
+        Ok(Route {
+            destination: IpAddr::V4(parse_ipv4(elems[1])?),
+            prefix_len: u32::from(parse_ipv4(elems[7])?).count_ones() as u8,
+            gateway: if gateway.is_unspecified() {
+                None
+            } else {
+                Some(IpAddr::V4(gateway))
+            },
+            interface: elems[0].to_string(),
+            metric: trim_parse_map::<u32>(elems[6])?,
+            flags: hex!(u32, elems[3])?,
+        })
+    }
+
+    pub(crate) fn from_ipv6_route_line(line: &str) -> Result<Route> {
+        let elems: Vec<&str> = line.split_ascii_whitespace().collect();
+        if elems.len() < 10 {
+            return Err(Error::InvalidInputError(
+                line.to_string(),
+                "expected 10 columns".to_string(),
+            ));
+        }
+
+        let gateway = Ipv6Addr::from(hex!(u128, elems[4])?);
+        Ok(Route {
+            destination: IpAddr::V6(Ipv6Addr::from(hex!(u128, elems[0])?)),
+            prefix_len: hex!(u8, elems[1])?,
+            gateway: if gateway.is_unspecified() {
+                None
+            } else {
+                Some(IpAddr::V6(gateway))
+            },
+            interface: elems[9].to_string(),
+            metric: hex!(u32, elems[5])?,
+            flags: hex!(u32, elems[8])?,
+        })
+    }
+
+    pub(crate) fn from_route(route: &str) -> Result<Vec<Route>> {
+        // first line is a header
+        route
+            .lines()
+            .skip(1)
+            .filter(|line| !line.trim().is_empty())
+            .map(Route::from_route_line)
+            .collect()
+    }
+
+    pub(crate) fn from_ipv6_route(route: &str) -> Result<Vec<Route>> {
+        route
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(Route::from_ipv6_route_line)
+            .collect()
+    }
+
+    /// Returns true if this route matches any destination
+    pub fn is_default(&self) -> bool {
+        self.prefix_len == 0
+    }
+
+    pub fn is_up(&self) -> bool {
+        self.flags & RTF_UP != 0
+    }
+
+    pub fn is_reject(&self) -> bool {
+        self.flags & RTF_REJECT != 0
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Default routes of both address families
+pub struct DefaultRoutes {
+    pub v4: Option<Route>,
+    pub v6: Option<Route>,
+}
+
+impl DefaultRoutes {
+    /// Picks usable default routes with the lowest metric out of `routes`
+    pub(crate) fn from_routes(routes: &[Route]) -> DefaultRoutes {
+        let best = |v4: bool| {
+            routes
+                .iter()
+                .filter(|r| r.destination.is_ipv4() == v4 && r.is_default() && r.is_up() && !r.is_reject())
+                .min_by_key(|r| r.metric)
+                .cloned()
+        };
+
+        DefaultRoutes {
+            v4: best(true),
+            v6: best(false),
+        }
+    }
+}
+
+/// Parses an IPv4 address printed by the kernel as hex of a network order integer in host
+/// byte order
+fn parse_ipv4(s: &str) -> Result<Ipv4Addr> {
+    Ok(Ipv4Addr::from(hex!(u32, s)?.to_ne_bytes()))
+}
+
+/// Returns IPv4 routes parsed from /proc/net/route
+pub fn ipv4_routes() -> Result<Vec<Route>> {
+    Route::from_route(&SysFs::Proc.join("net/route").read()?)
+}
+
+/// Returns IPv6 routes parsed from /proc/net/ipv6_route. If IPv6 is disabled there are no routes.
+pub fn ipv6_routes() -> Result<Vec<Route>> {
+    match SysFs::Proc.join("net/ipv6_route").read() {
+        Ok(route) => Route::from_ipv6_route(&route),
+        Err(Error::FileReadError(_, _)) => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns IPv4 and IPv6 routes parsed from /proc/net/route and /proc/net/ipv6_route
+pub fn routes() -> Result<Vec<Route>> {
+    let mut routes = ipv4_routes()?;
+    routes.extend(ipv6_routes()?);
+    Ok(routes)
+}
+
+/// Returns IPv4 and IPv6 default routes with the lowest metric
+pub fn default_route() -> Result<DefaultRoutes> {
+    Ok(DefaultRoutes::from_routes(&routes()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linux::mocks::{NET_IPV6_ROUTE, NET_ROUTE};
+
+    #[test]
+    fn parses_ipv4_routes() {
+        let routes = Route::from_route(NET_ROUTE).unwrap();
+        assert_eq!(routes.len(), 4);
+        assert_eq!(
+            routes[1],
+            Route {
+                destination: IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+                prefix_len: 0,
+                gateway: Some(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 254))),
+                interface: "eth0".to_string(),
+                metric: 100,
+                flags: RTF_UP | RTF_GATEWAY,
+            }
+        );
+        assert_eq!(routes[3].destination, IpAddr::V4(Ipv4Addr::new(172, 17, 0, 0)));
+        assert_eq!(routes[3].prefix_len, 16);
+        assert_eq!(routes[3].gateway, None);
+    }
+
+    #[test]
+    fn parses_ipv6_routes() {
+        let routes = Route::from_ipv6_route(NET_IPV6_ROUTE).unwrap();
+        assert_eq!(routes.len(), 4);
+        assert_eq!(routes[0].destination, IpAddr::V6("fd00::".parse::<Ipv6Addr>().unwrap()));
+        assert_eq!(routes[0].prefix_len, 64);
+        assert_eq!(routes[0].metric, 256);
+        assert_eq!(
+            routes[1].gateway,
+            Some(IpAddr::V6("fe80::1".parse::<Ipv6Addr>().unwrap()))
+        );
+        assert_eq!(routes[1].flags, RTF_UP | RTF_GATEWAY);
+        assert_eq!(routes[2].prefix_len, 128);
+        assert!(routes[3].is_reject());
+    }
+
+    #[test]
+    fn finds_default_routes() {
+        let mut routes = Route::from_route(NET_ROUTE).unwrap();
+        routes.extend(Route::from_ipv6_route(NET_IPV6_ROUTE).unwrap());
+        let default = DefaultRoutes::from_routes(&routes);
+        assert_eq!(default.v4.unwrap().interface, "eth0");
+        let v6 = default.v6.unwrap();
+        assert_eq!(v6.interface, "eth0");
+        assert_eq!(v6.metric, 1024);
+
+        assert_eq!(DefaultRoutes::from_routes(&routes[2..4]), DefaultRoutes::default());
+    }
+}
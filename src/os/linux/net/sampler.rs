@@ -0,0 +1,142 @@
+use crate::linux::net::{interfaces_stats, InterfaceStats};
+use crate::{util::counter_delta, Result};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents traffic of an interface in a single sampling interval
+pub struct InterfaceRates {
+    pub name: String,
+    /// Bytes received per second.
+    pub rx_bytes: f64,
+    /// Packets received per second.
+    pub rx_packets: f64,
+    /// Bytes transmitted per second.
+    pub tx_bytes: f64,
+    /// Packets transmitted per second.
+    pub tx_packets: f64,
+}
+
+#[derive(Clone, Debug, Default)]
+/// Samples traffic of all interfaces reading /proc/net/dev once per sample
+pub struct InterfaceSampler {
+    prev: HashMap<String, InterfaceStats>,
+    at: Option<Instant>,
+}
+
+impl InterfaceSampler {
+    /// Creates a new sampler taking the initial reading of /proc/net/dev
+    pub fn new() -> Result<InterfaceSampler> {
+        let mut sampler = InterfaceSampler::default();
+        sampler.sample()?;
+        Ok(sampler)
+    }
+
+    /// Returns rates of all interfaces seen both in this and the previous sample sorted by name
+    pub fn sample(&mut self) -> Result<Vec<InterfaceRates>> {
+        let stats = interfaces_stats()?;
+        Ok(self.update(stats, Instant::now()))
+    }
+
+    fn update(&mut self, stats: Vec<InterfaceStats>, now: Instant) -> Vec<InterfaceRates> {
+        let elapsed = self.at.map(|at| now.saturating_duration_since(at));
+        let mut rates = Vec::new();
+
+        if let Some(elapsed) = elapsed {
+            for cur in &stats {
+                if let Some(prev) = self.prev.get(&cur.name) {
+                    rates.push(interface_rates(prev, cur, elapsed));
+                }
+            }
+        }
+
+        self.prev = stats.into_iter().map(|s| (s.name.clone(), s)).collect();
+        self.at = Some(now);
+        rates.sort_by(|a, b| a.name.cmp(&b.name));
+        rates
+    }
+}
+
+fn interface_rates(prev: &InterfaceStats, cur: &InterfaceStats, elapsed: Duration) -> InterfaceRates {
+    if elapsed == Duration::from_secs(0) {
+        return InterfaceRates {
+            name: cur.name.clone(),
+            ..Default::default()
+        };
+    }
+    let secs = elapsed.as_secs_f64();
+    let rate = |prev: u64, cur: u64| counter_delta(prev, cur) as f64 / secs;
+
+    InterfaceRates {
+        name: cur.name.clone(),
+        rx_bytes: rate(prev.rx_bytes, cur.rx_bytes),
+        rx_packets: rate(prev.rx_packets, cur.rx_packets),
+        tx_bytes: rate(prev.tx_bytes, cur.tx_bytes),
+        tx_packets: rate(prev.tx_packets, cur.tx_packets),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(name: &str, rx_bytes: u64, tx_bytes: u64, rx_packets: u64) -> InterfaceStats {
+        InterfaceStats {
+            name: name.to_string(),
+            rx_bytes,
+            tx_bytes,
+            rx_packets,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn computes_interface_rates() {
+        let mut sampler = InterfaceSampler::default();
+        let start = Instant::now();
+
+        assert!(sampler
+            .update(vec![stats("lo", 0, 0, 0), stats("eth0", 1000, 500, 10)], start)
+            .is_empty());
+
+        let rates = sampler.update(
+            vec![
+                stats("eth0", 5000, 2500, 50),
+                stats("lo", 200, 200, 2),
+                stats("wg0", 100, 100, 1),
+            ],
+            start + Duration::from_secs(2),
+        );
+        assert_eq!(
+            rates,
+            vec![
+                InterfaceRates {
+                    name: "eth0".to_string(),
+                    rx_bytes: 2000.,
+                    rx_packets: 20.,
+                    tx_bytes: 1000.,
+                    tx_packets: 0.,
+                },
+                InterfaceRates {
+                    name: "lo".to_string(),
+                    rx_bytes: 100.,
+                    rx_packets: 1.,
+                    tx_bytes: 100.,
+                    tx_packets: 0.,
+                },
+            ]
+        );
+
+        // rx counter of eth0 was reset
+        let rates = sampler.update(vec![stats("eth0", 1999, 2500, 50)], start + Duration::from_secs(4));
+        assert_eq!(rates.len(), 1);
+        assert_eq!(rates[0].rx_bytes, 999.5);
+        assert_eq!(sampler.prev.len(), 1);
+    }
+}
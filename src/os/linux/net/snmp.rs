@@ -0,0 +1,425 @@
+use crate::linux::SysFs;
+use crate::{util::trim_parse_map, Error, Result};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// Counters of a single protocol mapped by the name they have in the source file
+pub type Counters = BTreeMap<String, i64>;
+
+/// Returns a counter with `key` or 0 if it's missing, for example because the kernel is too old
+fn counter(counters: &Counters, key: &str) -> u64 {
+    counters.get(key).map(|v| (*v).max(0) as u64).unwrap_or_default()
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// IPv4 or IPv6 counters since boot
+pub struct IpStats {
+    pub in_receives: u64,
+    pub in_hdr_errors: u64,
+    pub in_addr_errors: u64,
+    pub in_discards: u64,
+    pub in_delivers: u64,
+    pub out_requests: u64,
+    pub out_discards: u64,
+    pub out_no_routes: u64,
+}
+
+impl IpStats {
+    fn from_counters(counters: &Counters, prefix: &str) -> IpStats {
+        let get = |key: &str| counter(counters, &format!("{}{}", prefix, key));
+        IpStats {
+            in_receives: get("InReceives"),
+            in_hdr_errors: get("InHdrErrors"),
+            in_addr_errors: get("InAddrErrors"),
+            in_discards: get("InDiscards"),
+            in_delivers: get("InDelivers"),
+            out_requests: get("OutRequests"),
+            out_discards: get("OutDiscards"),
+            out_no_routes: get("OutNoRoutes"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// TCP counters since boot
+pub struct TcpStats {
+    pub active_opens: u64,
+    pub passive_opens: u64,
+    pub attempt_fails: u64,
+    pub estab_resets: u64,
+    /// Number of connections currently in ESTABLISHED or CLOSE-WAIT state.
+    pub curr_estab: u64,
+    pub in_segs: u64,
+    pub out_segs: u64,
+    pub retrans_segs: u64,
+    pub in_errs: u64,
+    pub out_rsts: u64,
+}
+
+impl TcpStats {
+    fn from_counters(counters: &Counters) -> TcpStats {
+        let get = |key: &str| counter(counters, key);
+        TcpStats {
+            active_opens: get("ActiveOpens"),
+            passive_opens: get("PassiveOpens"),
+            attempt_fails: get("AttemptFails"),
+            estab_resets: get("EstabResets"),
+            curr_estab: get("CurrEstab"),
+            in_segs: get("InSegs"),
+            out_segs: get("OutSegs"),
+            retrans_segs: get("RetransSegs"),
+            in_errs: get("InErrs"),
+            out_rsts: get("OutRsts"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// UDP counters since boot
+pub struct UdpStats {
+    pub in_datagrams: u64,
+    pub no_ports: u64,
+    pub in_errors: u64,
+    pub out_datagrams: u64,
+    /// Datagrams dropped because the receive buffer of the socket was full.
+    pub rcvbuf_errors: u64,
+    pub sndbuf_errors: u64,
+}
+
+impl UdpStats {
+    fn from_counters(counters: &Counters, prefix: &str) -> UdpStats {
+        let get = |key: &str| counter(counters, &format!("{}{}", prefix, key));
+        UdpStats {
+            in_datagrams: get("InDatagrams"),
+            no_ports: get("NoPorts"),
+            in_errors: get("InErrors"),
+            out_datagrams: get("OutDatagrams"),
+            rcvbuf_errors: get("RcvbufErrors"),
+            sndbuf_errors: get("SndbufErrors"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Extended TCP counters since boot
+pub struct TcpExtStats {
+    /// Times the accept queue of a listening socket was full.
+    pub listen_overflows: u64,
+    /// Connection requests dropped by listening sockets, including overflows.
+    pub listen_drops: u64,
+    pub syncookies_sent: u64,
+    pub syncookies_recv: u64,
+    pub syncookies_failed: u64,
+    pub tcp_timeouts: u64,
+    pub tcp_syn_retrans: u64,
+    pub tcp_fast_retrans: u64,
+    pub tcp_slow_start_retrans: u64,
+    pub tcp_lost_retransmit: u64,
+    pub tcp_backlog_drop: u64,
+    pub tcp_ofo_drop: u64,
+    pub tcp_abort_on_timeout: u64,
+}
+
+impl TcpExtStats {
+    fn from_counters(counters: &Counters) -> TcpExtStats {
+        let get = |key: &str| counter(counters, key);
+        TcpExtStats {
+            listen_overflows: get("ListenOverflows"),
+            listen_drops: get("ListenDrops"),
+            syncookies_sent: get("SyncookiesSent"),
+            syncookies_recv: get("SyncookiesRecv"),
+            syncookies_failed: get("SyncookiesFailed"),
+            tcp_timeouts: get("TCPTimeouts"),
+            tcp_syn_retrans: get("TCPSynRetrans"),
+            tcp_fast_retrans: get("TCPFastRetrans"),
+            tcp_slow_start_retrans: get("TCPSlowStartRetrans"),
+            tcp_lost_retransmit: get("TCPLostRetransmit"),
+            tcp_backlog_drop: get("TCPBacklogDrop"),
+            tcp_ofo_drop: get("TCPOFODrop"),
+            tcp_abort_on_timeout: get("TCPAbortOnTimeout"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents IPv4 protocol counters parsed from /proc/net/snmp
+pub struct Snmp {
+    pub ip: IpStats,
+    pub tcp: TcpStats,
+    pub udp: UdpStats,
+    /// All counters mapped by protocol, for example `Icmp` or `UdpLite`.
+    pub counters: HashMap<String, Counters>,
+}
+
+impl Snmp {
+    pub(crate) fn from_snmp(snmp: &str) -> Result<Snmp> {
+        let counters = parse_tables(snmp)?;
+        let empty = Counters::new();
+        let table = |name: &str| counters.get(name).unwrap_or(&empty);
+
+        Ok(Snmp {
+            ip: IpStats::from_counters(table("Ip"), ""),
+            tcp: TcpStats::from_counters(table("Tcp")),
+            udp: UdpStats::from_counters(table("Udp"), ""),
+            counters,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents IPv6 protocol counters parsed from /proc/net/snmp6
+pub struct Snmp6 {
+    pub ip: IpStats,
+    pub udp: UdpStats,
+    /// All counters mapped by name, for example `Icmp6InMsgs`.
+    pub counters: Counters,
+}
+
+impl Snmp6 {
+    pub(crate) fn from_snmp6(snmp6: &str) -> Result<Snmp6> {
+        let counters = parse_key_values(snmp6)?;
+        Ok(Snmp6 {
+            ip: IpStats::from_counters(&counters, "Ip6"),
+            udp: UdpStats::from_counters(&counters, "Udp6"),
+            counters,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents extended protocol counters parsed from /proc/net/netstat
+pub struct NetStat {
+    pub tcp_ext: TcpExtStats,
+    /// All counters mapped by table, for example `TcpExt`, `IpExt` or `MPTcpExt`.
+    pub counters: HashMap<String, Counters>,
+}
+
+impl NetStat {
+    pub(crate) fn from_netstat(netstat: &str) -> Result<NetStat> {
+        let counters = parse_tables(netstat)?;
+        Ok(NetStat {
+            tcp_ext: counters
+                .get("TcpExt")
+                .map(TcpExtStats::from_counters)
+                .unwrap_or_default(),
+            counters,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents socket usage parsed from /proc/net/sockstat. Memory is reported in pages.
+pub struct SockStat {
+    /// Total number of sockets of all protocols in use.
+    pub sockets_used: u64,
+    pub tcp_inuse: u64,
+    /// TCP sockets no longer attached to any process.
+    pub tcp_orphan: u64,
+    /// TCP sockets in TIME-WAIT state.
+    pub tcp_tw: u64,
+    pub tcp_alloc: u64,
+    pub tcp_mem: u64,
+    pub udp_inuse: u64,
+    pub udp_mem: u64,
+    pub udplite_inuse: u64,
+    pub raw_inuse: u64,
+    pub frag_inuse: u64,
+    pub frag_memory: u64,
+}
+
+impl SockStat {
+    pub(crate) fn from_sockstat(sockstat: &str) -> Result<SockStat> {
+        let mut stat = SockStat::default();
+        for line in sockstat.lines() {
+            let (protocol, values) = match line.split_once(':') {
+                Some(split) => split,
+                None => continue,
+            };
+            let mut elems = values.split_ascii_whitespace();
+            while let (Some(key), Some(value)) = (elems.next(), elems.next()) {
+                let value = trim_parse_map::<u64>(value)?;
+                match (protocol, key) {
+                    ("sockets", "used") => stat.sockets_used = value,
+                    ("TCP", "inuse") => stat.tcp_inuse = value,
+                    ("TCP", "orphan") => stat.tcp_orphan = value,
+                    ("TCP", "tw") => stat.tcp_tw = value,
+                    ("TCP", "alloc") => stat.tcp_alloc = value,
+                    ("TCP", "mem") => stat.tcp_mem = value,
+                    ("UDP", "inuse") => stat.udp_inuse = value,
+                    ("UDP", "mem") => stat.udp_mem = value,
+                    ("UDPLITE", "inuse") => stat.udplite_inuse = value,
+                    ("RAW", "inuse") => stat.raw_inuse = value,
+                    ("FRAG", "inuse") => stat.frag_inuse = value,
+                    ("FRAG", "memory") => stat.frag_memory = value,
+                    _ => {}
+                }
+            }
+        }
+        Ok(stat)
+    }
+}
+
+/// Parses tables where each protocol has a line with counter names followed by a line with
+/// values, both prefixed with the protocol name like `Tcp: ActiveOpens` and `Tcp: 184201`
+pub(crate) fn parse_tables(content: &str) -> Result<HashMap<String, Counters>> {
+    let mut tables = HashMap::new();
+    let mut lines = content.lines().filter(|line| !line.trim().is_empty());
+
+    while let Some(header) = lines.next() {
+        let values = lines
+            .next()
+            .ok_or_else(|| Error::InvalidInputError(header.to_string(), "missing line with values".to_string()))?;
+        let (name, keys) = header
+            .split_once(':')
+            .ok_or_else(|| Error::InvalidInputError(header.to_string(), "missing table name".to_string()))?;
+        let (value_name, values) = values
+            .split_once(':')
+            .ok_or_else(|| Error::InvalidInputError(values.to_string(), "missing table name".to_string()))?;
+        if name != value_name {
+            return Err(Error::InvalidInputError(
+                header.to_string(),
+                format!("header of `{}` followed by values of `{}`", name, value_name),
+            ));
+        }
+
+        let keys: Vec<&str> = keys.split_ascii_whitespace().collect();
+        let values: Vec<&str> = values.split_ascii_whitespace().collect();
+        if keys.len() != values.len() {
+            return Err(Error::InvalidInputError(
+                header.to_string(),
+                format!("{} counter names but {} values", keys.len(), values.len()),
+            ));
+        }
+
+        let mut counters = Counters::new();
+        for (key, value) in keys.into_iter().zip(values) {
+            counters.insert(key.to_string(), trim_parse_map::<i64>(value)?);
+        }
+        tables.insert(name.to_string(), counters);
+    }
+
+    Ok(tables)
+}
+
+/// Parses lines of whitespace separated counter names and values
+pub(crate) fn parse_key_values(content: &str) -> Result<Counters> {
+    let mut counters = Counters::new();
+    for line in content.lines() {
+        let mut elems = line.split_ascii_whitespace();
+        if let (Some(key), Some(value)) = (elems.next(), elems.next()) {
+            counters.insert(key.to_string(), trim_parse_map::<i64>(value)?);
+        }
+    }
+    Ok(counters)
+}
+
+/// Returns IPv4 protocol counters parsed from /proc/net/snmp
+pub fn snmp() -> Result<Snmp> {
+    Snmp::from_snmp(&SysFs::Proc.join("net/snmp").read()?)
+}
+
+/// Returns IPv6 protocol counters parsed from /proc/net/snmp6
+pub fn snmp6() -> Result<Snmp6> {
+    Snmp6::from_snmp6(&SysFs::Proc.join("net/snmp6").read()?)
+}
+
+/// Returns extended protocol counters parsed from /proc/net/netstat
+pub fn netstat() -> Result<NetStat> {
+    NetStat::from_netstat(&SysFs::Proc.join("net/netstat").read()?)
+}
+
+/// Returns socket usage parsed from /proc/net/sockstat
+pub fn sockstat() -> Result<SockStat> {
+    SockStat::from_sockstat(&SysFs::Proc.join("net/sockstat").read()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linux::mocks::{NET_NETSTAT, NET_SNMP, NET_SNMP6, NET_SOCKSTAT};
+
+    #[test]
+    fn parses_snmp() {
+        let snmp = Snmp::from_snmp(NET_SNMP).unwrap();
+        assert_eq!(
+            snmp.tcp,
+            TcpStats {
+                active_opens: 184_201,
+                passive_opens: 3210,
+                attempt_fails: 4182,
+                estab_resets: 2211,
+                curr_estab: 37,
+                in_segs: 27_851_123,
+                out_segs: 26_420_981,
+                retrans_segs: 48213,
+                in_errs: 19,
+                out_rsts: 9823,
+            }
+        );
+        assert_eq!(snmp.udp.rcvbuf_errors, 81);
+        assert_eq!(snmp.udp.no_ports, 1267);
+        assert_eq!(snmp.ip.in_receives, 28_410_412);
+        assert_eq!(snmp.ip.out_no_routes, 12);
+        assert_eq!(snmp.counters["Tcp"]["MaxConn"], -1);
+        assert_eq!(snmp.counters["Icmp"]["InDestUnreachs"], 1180);
+        assert_eq!(snmp.counters.len(), 5);
+    }
+
+    #[test]
+    fn parses_snmp6() {
+        let snmp6 = Snmp6::from_snmp6(NET_SNMP6).unwrap();
+        assert_eq!(snmp6.ip.in_receives, 91821);
+        assert_eq!(snmp6.ip.in_addr_errors, 3);
+        assert_eq!(snmp6.udp.rcvbuf_errors, 4);
+        assert_eq!(snmp6.udp.out_datagrams, 1790);
+        assert_eq!(snmp6.counters["Icmp6InMsgs"], 412);
+    }
+
+    #[test]
+    fn parses_netstat() {
+        let netstat = NetStat::from_netstat(NET_NETSTAT).unwrap();
+        assert_eq!(netstat.tcp_ext.listen_overflows, 321);
+        assert_eq!(netstat.tcp_ext.listen_drops, 345);
+        assert_eq!(netstat.tcp_ext.tcp_syn_retrans, 1193);
+        assert_eq!(netstat.tcp_ext.tcp_abort_on_timeout, 77);
+        assert_eq!(netstat.counters["IpExt"]["InOctets"], 38_412_094_123);
+    }
+
+    #[test]
+    fn parses_sockstat() {
+        assert_eq!(
+            SockStat::from_sockstat(NET_SOCKSTAT).unwrap(),
+            SockStat {
+                sockets_used: 1432,
+                tcp_inuse: 42,
+                tcp_orphan: 1,
+                tcp_tw: 17,
+                tcp_alloc: 58,
+                tcp_mem: 12,
+                udp_inuse: 9,
+                udp_mem: 4,
+                udplite_inuse: 0,
+                raw_inuse: 1,
+                frag_inuse: 0,
+                frag_memory: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn fails_on_mismatched_tables() {
+        assert!(parse_tables("Tcp: A B\nTcp: 1\n").is_err());
+        assert!(parse_tables("Tcp: A B\nUdp: 1 2\n").is_err());
+        assert!(parse_tables("Tcp: A B\n").is_err());
+    }
+}
@@ -0,0 +1,346 @@
+use crate::linux::{
+    ps::{pids, socket_inodes},
+    SysFs,
+};
+use crate::{util::trim_parse_map, Error, Result};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+};
+
+/// __SO_ACCEPTCON flag of unix sockets marking a listening socket
+const SO_ACCEPTCON: u32 = 0x10000;
+/// SS_CONNECTED state of unix sockets
+const SS_CONNECTED: u8 = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Socket table in /proc/net
+pub enum SocketProtocol {
+    Tcp,
+    Tcp6,
+    Udp,
+    Udp6,
+    Raw,
+    Unix,
+}
+
+impl SocketProtocol {
+    /// All supported socket tables
+    pub const ALL: [SocketProtocol; 6] = [
+        SocketProtocol::Tcp,
+        SocketProtocol::Tcp6,
+        SocketProtocol::Udp,
+        SocketProtocol::Udp6,
+        SocketProtocol::Raw,
+        SocketProtocol::Unix,
+    ];
+
+    /// Name of the file in /proc/net containing the table
+    pub fn table(&self) -> &'static str {
+        match self {
+            SocketProtocol::Tcp => "tcp",
+            SocketProtocol::Tcp6 => "tcp6",
+            SocketProtocol::Udp => "udp",
+            SocketProtocol::Udp6 => "udp6",
+            SocketProtocol::Raw => "raw",
+            SocketProtocol::Unix => "unix",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// State of a socket. Tcp states are reported as is, unconnected udp and raw sockets are in
+/// `Close` state and unix sockets are either `Listen`, `Established` or `Close`.
+pub enum SocketState {
+    Established,
+    SynSent,
+    SynRecv,
+    FinWait1,
+    FinWait2,
+    TimeWait,
+    Close,
+    CloseWait,
+    LastAck,
+    Listen,
+    Closing,
+    NewSynRecv,
+    Unknown(u8),
+}
+
+impl From<u8> for SocketState {
+    fn from(state: u8) -> Self {
+        match state {
+            0x01 => SocketState::Established,
+            0x02 => SocketState::SynSent,
+            0x03 => SocketState::SynRecv,
+            0x04 => SocketState::FinWait1,
+            0x05 => SocketState::FinWait2,
+            0x06 => SocketState::TimeWait,
+            0x07 => SocketState::Close,
+            0x08 => SocketState::CloseWait,
+            0x09 => SocketState::LastAck,
+            0x0A => SocketState::Listen,
+            0x0B => SocketState::Closing,
+            0x0C => SocketState::NewSynRecv,
+            state => SocketState::Unknown(state),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents a socket parsed from one of the tables in /proc/net
+pub struct Socket {
+    pub protocol: SocketProtocol,
+    /// Local address of an inet socket.
+    pub local: Option<SocketAddr>,
+    /// Remote address of an inet socket.
+    pub remote: Option<SocketAddr>,
+    /// Path a unix socket is bound to. Abstract sockets start with `@`.
+    pub path: Option<String>,
+    pub state: SocketState,
+    pub inode: u64,
+    /// Owner of an inet socket, not available for unix sockets.
+    pub uid: Option<u32>,
+    pub tx_queue: u64,
+    pub rx_queue: u64,
+    /// Processes holding a file descriptor of this socket. Only processes whose file descriptors
+    /// are readable by the caller are found, which for other users requires privileges.
+    pub pids: Vec<i32>,
+}
+
+impl Socket {
+    pub(crate) fn from_inet_line(protocol: SocketProtocol, line: &str) -> Result<Socket> {
+        let elems: Vec<&str> = line.split_ascii_whitespace().collect();
+        if elems.len() < 10 {
+            return Err(Error::InvalidInputError(
+                line.to_string(),
+                "expected at least 10 columns".to_string(),
+            ));
+        }
+        let (tx_queue, rx_queue) = split_pair(elems[4])?;
+
+        Ok(Socket {
+            protocol,
+            local: Some(parse_socket_addr(elems[1])?),
+            remote: Some(parse_socket_addr(elems[2])?),
+            path: None,
+            state: SocketState::from(hex!(u8, elems[3])?),
+            inode: trim_parse_map::<u64>(elems[9])?,
+            uid: Some(trim_parse_map::<u32>(elems[7])?),
+            tx_queue: hex!(u64, tx_queue)?,
+            rx_queue: hex!(u64, rx_queue)?,
+            pids: Vec::new(),
+        })
+    }
+
+    pub(crate) fn from_unix_line(line: &str) -> Result<Socket> {
+        let mut elems = line.split_ascii_whitespace();
+        let mut column = || {
+            elems
+                .next()
+                .ok_or_else(|| Error::InvalidInputError(line.to_string(), "expected at least 7 columns".to_string()))
+        };
+        let _num = column()?;
+        let _refcount = column()?;
+        let _protocol = column()?;
+        let flags = column()?;
+        let _type = column()?;
+        let state = column()?;
+        let (flags, state) = (hex!(u32, flags)?, hex!(u8, state)?);
+        let inode = trim_parse_map::<u64>(column()?)?;
+        let path = elems.collect::<Vec<_>>().join(" ");
+
+        Ok(Socket {
+            protocol: SocketProtocol::Unix,
+            local: None,
+            remote: None,
+            path: if path.is_empty() { None } else { Some(path) },
+            state: if flags & SO_ACCEPTCON != 0 {
+                SocketState::Listen
+            } else if state == SS_CONNECTED {
+                SocketState::Established
+            } else {
+                SocketState::Close
+            },
+            inode,
+            uid: None,
+            tx_queue: 0,
+            rx_queue: 0,
+            pids: Vec::new(),
+        })
+    }
+
+    pub(crate) fn from_table(protocol: SocketProtocol, table: &str) -> Result<Vec<Socket>> {
+        // first line is a header
+        table
+            .lines()
+            .skip(1)
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| match protocol {
+                SocketProtocol::Unix => Socket::from_unix_line(line),
+                _ => Socket::from_inet_line(protocol, line),
+            })
+            .collect()
+    }
+
+    /// Returns true if this socket accepts connections or, for udp and raw sockets, is bound
+    /// without being connected to a peer
+    pub fn is_listening(&self) -> bool {
+        match self.protocol {
+            SocketProtocol::Udp | SocketProtocol::Udp6 | SocketProtocol::Raw => {
+                self.state == SocketState::Close && self.remote.map(|r| r.port() == 0).unwrap_or(false)
+            }
+            _ => self.state == SocketState::Listen,
+        }
+    }
+}
+
+fn split_pair(s: &str) -> Result<(&str, &str)> {
+    s.split_once(':')
+        .ok_or_else(|| Error::InvalidInputError(s.to_string(), "missing `:` separator".to_string()))
+}
+
+/// Parses an address like `0100007F:1F90` where the ip is printed as hex of network order
+/// 32 bit words in host byte order and the port in host byte order
+fn parse_socket_addr(s: &str) -> Result<SocketAddr> {
+    let (ip, port) = split_pair(s)?;
+    let port = hex!(u16, port)?;
+    let ip = match ip.len() {
+        8 => IpAddr::V4(Ipv4Addr::from(hex!(u32, ip)?.to_ne_bytes())),
+        32 if ip.is_ascii() => {
+            let mut octets = [0u8; 16];
+            for (i, chunk) in octets.chunks_mut(4).enumerate() {
+                let word = &ip[i * 8..i * 8 + 8];
+                chunk.copy_from_slice(&hex!(u32, word)?.to_ne_bytes());
+            }
+            IpAddr::V6(Ipv6Addr::from(octets))
+        }
+        _ => {
+            return Err(Error::InvalidInputError(
+                s.to_string(),
+                "invalid length of ip address".to_string(),
+            ))
+        }
+    };
+    Ok(SocketAddr::new(ip, port))
+}
+
+/// Returns pids of processes owning each socket mapped by socket inode. Processes that exit or
+/// whose file descriptors are not readable are skipped.
+pub(crate) fn socket_owners() -> Result<HashMap<u64, Vec<i32>>> {
+    let mut owners: HashMap<u64, Vec<i32>> = HashMap::new();
+    for pid in pids()? {
+        let inodes = match socket_inodes(&SysFs::Proc.join(pid.to_string())) {
+            Ok(inodes) => inodes,
+            Err(Error::FileReadError(_, _)) | Err(Error::PermissionDenied(_)) => continue,
+            Err(e) => return Err(e),
+        };
+        for inode in inodes {
+            let pids = owners.entry(inode).or_default();
+            if !pids.contains(&pid) {
+                pids.push(pid);
+            }
+        }
+    }
+    Ok(owners)
+}
+
+fn read_table(protocol: SocketProtocol) -> Result<Vec<Socket>> {
+    match SysFs::Proc.join("net").join(protocol.table()).read() {
+        Ok(table) => Socket::from_table(protocol, &table),
+        // table is missing if the protocol is disabled, for example IPv6
+        Err(Error::FileReadError(_, _)) => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+fn with_owners(mut sockets: Vec<Socket>) -> Result<Vec<Socket>> {
+    let owners = socket_owners()?;
+    for socket in sockets.iter_mut() {
+        if let Some(pids) = owners.get(&socket.inode) {
+            socket.pids = pids.clone();
+        }
+    }
+    Ok(sockets)
+}
+
+/// Returns sockets of `protocol` parsed from /proc/net/[protocol] with their owning processes
+pub fn protocol_sockets(protocol: SocketProtocol) -> Result<Vec<Socket>> {
+    with_owners(read_table(protocol)?)
+}
+
+/// Returns sockets of all supported protocols with their owning processes
+pub fn sockets() -> Result<Vec<Socket>> {
+    let mut sockets = Vec::new();
+    for protocol in SocketProtocol::ALL.iter() {
+        sockets.extend(read_table(*protocol)?);
+    }
+    with_owners(sockets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linux::mocks::{NET_TCP, NET_TCP6, NET_UDP, NET_UNIX};
+
+    #[test]
+    fn parses_tcp() {
+        let sockets = Socket::from_table(SocketProtocol::Tcp, NET_TCP).unwrap();
+        assert_eq!(sockets.len(), 3);
+        assert_eq!(sockets[0].local, Some("0.0.0.0:22".parse().unwrap()));
+        assert!(sockets[0].is_listening());
+        assert_eq!(sockets[1].rx_queue, 5);
+        assert_eq!(sockets[1].uid, Some(1000));
+        assert_eq!(
+            sockets[2],
+            Socket {
+                protocol: SocketProtocol::Tcp,
+                local: Some("192.168.1.2:50000".parse().unwrap()),
+                remote: Some("203.29.76.45:443".parse().unwrap()),
+                path: None,
+                state: SocketState::Established,
+                inode: 33012,
+                uid: Some(1000),
+                tx_queue: 0x120,
+                rx_queue: 0,
+                pids: vec![],
+            }
+        );
+        assert!(!sockets[2].is_listening());
+    }
+
+    #[test]
+    fn parses_tcp6() {
+        let sockets = Socket::from_table(SocketProtocol::Tcp6, NET_TCP6).unwrap();
+        assert_eq!(sockets[0].local, Some("[::]:22".parse().unwrap()));
+        assert_eq!(sockets[1].local, Some("[::ffff:127.0.0.1]:8080".parse().unwrap()));
+        assert_eq!(sockets[1].state, SocketState::TimeWait);
+    }
+
+    #[test]
+    fn parses_udp() {
+        let sockets = Socket::from_table(SocketProtocol::Udp, NET_UDP).unwrap();
+        assert_eq!(sockets[0].local, Some("127.0.0.53:53".parse().unwrap()));
+        assert_eq!(sockets[0].uid, Some(101));
+        assert!(sockets[0].is_listening());
+    }
+
+    #[test]
+    fn parses_unix() {
+        let sockets = Socket::from_table(SocketProtocol::Unix, NET_UNIX).unwrap();
+        assert_eq!(sockets.len(), 3);
+        assert_eq!(sockets[0].path.as_deref(), Some("/run/systemd/private"));
+        assert!(sockets[0].is_listening());
+        assert_eq!(sockets[1].path.as_deref(), Some("@/org/kernel/udev/udevd"));
+        assert_eq!(sockets[1].state, SocketState::Close);
+        assert_eq!(sockets[2].path, None);
+        assert_eq!(sockets[2].state, SocketState::Established);
+        assert_eq!(sockets[2].inode, 34567);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::linux::{SysFs, SysPath};
+use crate::{util::next, Error, Result};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::str::SplitAsciiWhitespace;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Traffic counters of a network interface since it was created.
+///
+/// Fields follow the columns of /proc/net/dev. When read from /sys/class/net/[iface]/statistics
+/// error counters are summed up the same way the kernel does for /proc/net/dev, so both sources
+/// report equal values.
+pub struct InterfaceStats {
+    pub name: String,
+    pub rx_bytes: u64,
+    pub rx_packets: u64,
+    pub rx_errs: u64,
+    /// Packets dropped by the kernel and missed by the device.
+    pub rx_drop: u64,
+    pub rx_fifo: u64,
+    /// Frame alignment, length, overrun and crc errors.
+    pub rx_frame: u64,
+    pub rx_compressed: u64,
+    pub rx_multicast: u64,
+    pub tx_bytes: u64,
+    pub tx_packets: u64,
+    pub tx_errs: u64,
+    pub tx_drop: u64,
+    pub tx_fifo: u64,
+    pub tx_colls: u64,
+    /// Carrier, aborted, window and heartbeat errors.
+    pub tx_carrier: u64,
+    pub tx_compressed: u64,
+}
+
+impl InterfaceStats {
+    pub(crate) fn from_net_dev_line(line: &str) -> Result<InterfaceStats> {
+        let (name, counters) = line
+            .split_once(':')
+            .ok_or_else(|| Error::InvalidInputError(line.to_string(), "missing interface name".to_string()))?;
+        let mut elems = counters.split_ascii_whitespace();
+
+        macro_rules! _next {
+            () => {
+                next::<u64, SplitAsciiWhitespace>(&mut elems, &line)?
+            };
+        }
+
+        Ok(InterfaceStats {
+            name: name.trim().to_string(),
+            rx_bytes: _next!(),
+            rx_packets: _next!(),
+            rx_errs: _next!(),
+            rx_drop: _next!(),
+            rx_fifo: _next!(),
+            rx_frame: _next!(),
+            rx_compressed: _next!(),
+            rx_multicast: _next!(),
+            tx_bytes: _next!(),
+            tx_packets: _next!(),
+            tx_errs: _next!(),
+            tx_drop: _next!(),
+            tx_fifo: _next!(),
+            tx_colls: _next!(),
+            tx_carrier: _next!(),
+            tx_compressed: _next!(),
+        })
+    }
+
+    pub(crate) fn from_net_dev(net_dev: &str) -> Result<Vec<InterfaceStats>> {
+        // first two lines are column headers
+        net_dev
+            .lines()
+            .skip(2)
+            .filter(|line| !line.trim().is_empty())
+            .map(InterfaceStats::from_net_dev_line)
+            .collect()
+    }
+
+    /// Reads counters of an interface from /sys/class/net/[iface]/statistics where `path` is
+    /// the directory of the interface
+    pub(crate) fn from_sys_path(path: &SysPath) -> Result<InterfaceStats> {
+        let stats = path.extend("statistics");
+        let read = |name: &str| stats.extend(name).read_as::<u64>();
+        // Counters that not every kernel exposes
+        let read_opt = |name: &str| read(name).or_else(|e| if missing(&e) { Ok(0) } else { Err(e) });
+
+        Ok(InterfaceStats {
+            name: path
+                .as_path()
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            rx_bytes: read("rx_bytes")?,
+            rx_packets: read("rx_packets")?,
+            rx_errs: read("rx_errors")?,
+            rx_drop: read("rx_dropped")? + read_opt("rx_missed_errors")?,
+            rx_fifo: read("rx_fifo_errors")?,
+            rx_frame: read("rx_frame_errors")?
+                + read_opt("rx_length_errors")?
+                + read_opt("rx_over_errors")?
+                + read_opt("rx_crc_errors")?,
+            rx_compressed: read("rx_compressed")?,
+            rx_multicast: read("multicast")?,
+            tx_bytes: read("tx_bytes")?,
+            tx_packets: read("tx_packets")?,
+            tx_errs: read("tx_errors")?,
+            tx_drop: read("tx_dropped")?,
+            tx_fifo: read("tx_fifo_errors")?,
+            tx_colls: read("collisions")?,
+            tx_carrier: read("tx_carrier_errors")?
+                + read_opt("tx_aborted_errors")?
+                + read_opt("tx_window_errors")?
+                + read_opt("tx_heartbeat_errors")?,
+            tx_compressed: read("tx_compressed")?,
+        })
+    }
+}
+
+fn missing(e: &Error) -> bool {
+    matches!(e, Error::FileReadError(_, _))
+}
+
+/// Returns traffic counters of all interfaces parsed from /proc/net/dev
+pub fn interfaces_stats() -> Result<Vec<InterfaceStats>> {
+    InterfaceStats::from_net_dev(&SysFs::Proc.join("net/dev").read()?)
+}
+
+/// Returns traffic counters of interface `name` read from /sys/class/net/[name]/statistics
+pub fn interface_stats(name: &str) -> Result<InterfaceStats> {
+    InterfaceStats::from_sys_path(&SysFs::Sys.join("class/net").join(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linux::mocks::NET_DEV;
+    use std::{fs, io};
+
+    fn eth0() -> InterfaceStats {
+        InterfaceStats {
+            name: "eth0".to_string(),
+            rx_bytes: 1_927_312_374,
+            rx_packets: 1_532_411,
+            rx_errs: 3,
+            rx_drop: 12,
+            rx_fifo: 1,
+            rx_frame: 2,
+            rx_compressed: 0,
+            rx_multicast: 8411,
+            tx_bytes: 84_738_123,
+            tx_packets: 823_114,
+            tx_errs: 0,
+            tx_drop: 4,
+            tx_fifo: 0,
+            tx_colls: 7,
+            tx_carrier: 1,
+            tx_compressed: 0,
+        }
+    }
+
+    #[test]
+    fn parses_net_dev() {
+        let stats = InterfaceStats::from_net_dev(NET_DEV).unwrap();
+        assert_eq!(
+            stats.iter().map(|s| s.name.as_str()).collect::<Vec<_>>(),
+            vec!["lo", "eth0", "wlp3s0"]
+        );
+        assert_eq!(stats[0].rx_bytes, 35_074_883);
+        assert_eq!(stats[0].tx_packets, 5297);
+        assert_eq!(stats[1], eth0());
+        assert_eq!(stats[2].rx_bytes, 0);
+    }
+
+    #[test]
+    fn reads_sys_statistics() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let stats = dir.path().join("eth0").join("statistics");
+        fs::create_dir_all(&stats)?;
+        for (name, value) in &[
+            ("rx_bytes", 1_927_312_374),
+            ("rx_packets", 1_532_411),
+            ("rx_errors", 3),
+            ("rx_dropped", 10),
+            ("rx_missed_errors", 2),
+            ("rx_fifo_errors", 1),
+            ("rx_frame_errors", 1),
+            ("rx_crc_errors", 1),
+            ("rx_compressed", 0),
+            ("multicast", 8411),
+            ("tx_bytes", 84_738_123),
+            ("tx_packets", 823_114),
+            ("tx_errors", 0),
+            ("tx_dropped", 4),
+            ("tx_fifo_errors", 0),
+            ("collisions", 7),
+            ("tx_carrier_errors", 1),
+            ("tx_compressed", 0),
+        ] {
+            fs::write(stats.join(name), format!("{}\n", value))?;
+        }
+
+        let path = SysFs::Custom(dir.path().join("eth0")).into_syspath();
+        assert_eq!(InterfaceStats::from_sys_path(&path).unwrap(), eth0());
+        Ok(())
+    }
+
+    #[test]
+    fn fails_on_invalid_line() {
+        assert!(InterfaceStats::from_net_dev_line("eth0 1 2 3").is_err());
+        assert!(InterfaceStats::from_net_dev_line("eth0: 1 2 3").is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use super::{cpu::*, kernel_release, mem::*, mounts::*, ps::*, Linux};
+use super::{cpu::*, kernel_release, kernel_stat, loadavg, mem::*, mounts::*, ps::*, KernelStat, Linux, LoadAvg};
 use crate::Result;
 
 /// Trait extending Rsys functionality with linux specific api
@@ -7,17 +7,8 @@ pub trait OsImplExt {
     // mem
     //
 
-    /// Returns the total amount of shared RAM in Bytes.
-    fn memory_shared(&self) -> Result<usize>;
-
-    /// Returns the total amount of memory used by buffers in Bytes.
-    fn memory_buffered(&self) -> Result<usize>;
-
-    /// Returns the total high memory size in Bytes.
-    fn memory_high_total(&self) -> Result<usize>;
-
-    /// Returns the total amount of unused high memory size in Bytes.
-    fn memory_high_free(&self) -> Result<usize>;
+    /// Returns detailed memory statistics parsed from /proc/meminfo
+    fn meminfo(&self) -> Result<MemInfo>;
 
     //
     // ps
@@ -32,6 +23,9 @@ pub trait OsImplExt {
     /// Returns all processes currently seen in /proc parsed as Processes
     fn processes(&self) -> Result<Processes>;
 
+    /// Returns a ProcessTree built from a single scan of processes in /proc
+    fn process_tree(&self) -> Result<ProcessTree>;
+
     //
     // other
     //
@@ -42,6 +36,12 @@ pub trait OsImplExt {
     /// Returns MountPoints read from /proc/mounts
     fn mounts(&self) -> Result<MountPoints>;
 
+    /// Returns system wide kernel counters parsed from /proc/stat
+    fn kernel_stat(&self) -> Result<KernelStat>;
+
+    /// Returns load average and scheduler statistics parsed from /proc/loadavg
+    fn loadavg(&self) -> Result<LoadAvg>;
+
     //
     // cpu
     //
@@ -58,20 +58,8 @@ impl OsImplExt for Linux {
     // mem
     //
 
-    fn memory_shared(&self) -> Result<usize> {
-        memory_shared()
-    }
-
-    fn memory_buffered(&self) -> Result<usize> {
-        memory_buffered()
-    }
-
-    fn memory_high_total(&self) -> Result<usize> {
-        memory_high_total()
-    }
-
-    fn memory_high_free(&self) -> Result<usize> {
-        memory_high_free()
+    fn meminfo(&self) -> Result<MemInfo> {
+        self.scoped(meminfo)
     }
 
     //
@@ -79,15 +67,19 @@ impl OsImplExt for Linux {
     //
 
     fn stat_process(&self, pid: i32) -> Result<ProcessStat> {
-        stat_process(pid)
+        self.scoped(|| stat_process(pid))
     }
 
     fn pids(&self) -> Result<Vec<i32>> {
-        pids()
+        self.scoped(pids)
     }
 
     fn processes(&self) -> Result<Processes> {
-        processes()
+        self.scoped(processes)
+    }
+
+    fn process_tree(&self) -> Result<ProcessTree> {
+        self.scoped(process_tree)
     }
 
     //
@@ -99,7 +91,15 @@ impl OsImplExt for Linux {
     }
 
     fn mounts(&self) -> Result<MountPoints> {
-        mounts()
+        self.scoped(mounts)
+    }
+
+    fn kernel_stat(&self) -> Result<KernelStat> {
+        self.scoped(kernel_stat)
+    }
+
+    fn loadavg(&self) -> Result<LoadAvg> {
+        self.scoped(loadavg)
     }
 
     //
@@ -107,10 +107,10 @@ impl OsImplExt for Linux {
     //
 
     fn cores(&self) -> Result<Cores> {
-        cores()
+        self.scoped(cores)
     }
 
     fn processor(&self) -> Result<Processor> {
-        processor()
+        self.scoped(processor)
     }
 }
@@ -0,0 +1,278 @@
+use crate::linux::SysPath;
+use crate::{util::trim_parse_map, Error, Result};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    os::unix::fs::FileTypeExt,
+    path::{Path, PathBuf},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// What an open file descriptor refers to
+pub enum FdTarget {
+    /// A file on a filesystem. The path may end with ` (deleted)` if the file was removed.
+    Path(PathBuf),
+    /// A character or block device file like /dev/null or /dev/sda
+    Device(PathBuf),
+    /// A socket with its inode number
+    Socket(u64),
+    /// A pipe with its inode number
+    Pipe(u64),
+    /// A file descriptor without an inode like `eventfd`, `eventpoll`, `inotify` or `timerfd`
+    AnonInode(String),
+    /// Any other target like a namespace `net:[4026531840]`
+    Other(String),
+}
+
+impl FdTarget {
+    pub(crate) fn from_link(link: &Path) -> FdTarget {
+        let s = link.to_string_lossy();
+        if let Some(inode) = Self::inode_of(&s, "socket:") {
+            FdTarget::Socket(inode)
+        } else if let Some(inode) = Self::inode_of(&s, "pipe:") {
+            FdTarget::Pipe(inode)
+        } else if let Some(kind) = s.strip_prefix("anon_inode:") {
+            FdTarget::AnonInode(kind.trim_start_matches('[').trim_end_matches(']').to_string())
+        } else if s.starts_with('/') {
+            FdTarget::Path(link.to_path_buf())
+        } else {
+            FdTarget::Other(s.to_string())
+        }
+    }
+
+    /// Parses inode out of links like `socket:[12345]`
+    fn inode_of(link: &str, prefix: &str) -> Option<u64> {
+        link.strip_prefix(prefix)?
+            .strip_prefix('[')?
+            .strip_suffix(']')?
+            .parse::<u64>()
+            .ok()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents information about a file descriptor parsed from /proc/[pid]/fdinfo/[fd]
+pub struct FdInfo {
+    /// Current file offset.
+    pub pos: u64,
+    /// File access mode and status flags as passed to open(2).
+    pub flags: u32,
+    /// Id of the mount containing the file, matches the mount id of /proc/[pid]/mountinfo.
+    pub mnt_id: u64,
+    /// Inode number of the file, not available on kernels older than 5.14.
+    pub ino: Option<u64>,
+}
+
+impl FdInfo {
+    pub(crate) fn from_fdinfo(fdinfo: &str) -> Result<FdInfo> {
+        let mut info = FdInfo::default();
+        for line in fdinfo.lines() {
+            let mut elems = line.splitn(2, ':');
+            let key = elems.next().unwrap_or_default().trim();
+            let value = elems.next().unwrap_or_default().trim();
+
+            match key {
+                "pos" => info.pos = trim_parse_map(value)?,
+                "flags" => {
+                    info.flags = u32::from_str_radix(value, 8)
+                        .map_err(|e| Error::InvalidInputError(line.to_string(), e.to_string()))?
+                }
+                "mnt_id" => info.mnt_id = trim_parse_map(value)?,
+                "ino" => info.ino = Some(trim_parse_map(value)?),
+                _ => {}
+            }
+        }
+        Ok(info)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents an open file descriptor of a process
+pub struct FileDescriptor {
+    pub fd: u32,
+    pub target: FdTarget,
+    pub info: FdInfo,
+}
+
+impl FileDescriptor {
+    /// Reads all open file descriptors of a process located at `path`. File descriptors closed
+    /// while reading are skipped.
+    pub(crate) fn from_sys_path(path: &SysPath) -> Result<Vec<FileDescriptor>> {
+        let fd_path = path.extend("fd");
+        let fdinfo_path = path.extend("fdinfo");
+        let mut fds = Vec::new();
+        for entry in fd_path.read_dir()?.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let fd = match name.parse::<u32>() {
+                Ok(fd) => fd,
+                Err(_) => continue,
+            };
+
+            match Self::read_fd(fd, &fd_path.extend(&name), &fdinfo_path.extend(&name)) {
+                Ok(fd) => fds.push(fd),
+                // descriptor was closed in the meantime
+                Err(Error::FileReadError(_, _)) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        fds.sort_by_key(|fd| fd.fd);
+
+        Ok(fds)
+    }
+
+    fn read_fd(fd: u32, link: &SysPath, fdinfo: &SysPath) -> Result<FileDescriptor> {
+        let target = match FdTarget::from_link(&link.read_link()?) {
+            FdTarget::Path(path) if is_device(link.as_path()) => FdTarget::Device(path),
+            target => target,
+        };
+        Ok(FileDescriptor {
+            fd,
+            target,
+            info: FdInfo::from_fdinfo(&fdinfo.read()?)?,
+        })
+    }
+}
+
+/// Checks the type of the file open at `fd` rather than its path, as files in /dev/shm or
+/// /dev/mqueue are not devices.
+fn is_device(fd: &Path) -> bool {
+    fs::metadata(fd).is_ok_and(|metadata| {
+        let file_type = metadata.file_type();
+        file_type.is_char_device() || file_type.is_block_device()
+    })
+}
+
+/// Returns inodes of sockets opened by a process located at `path`. Unlike
+/// `FileDescriptor::from_sys_path` this only resolves links in /proc/[pid]/fd and skips fdinfo.
+pub(crate) fn socket_inodes(path: &SysPath) -> Result<Vec<u64>> {
+    let mut inodes = Vec::new();
+    for entry in path.extend("fd").read_dir()?.flatten() {
+        // descriptor could be closed in the meantime
+        if let Ok(link) = fs::read_link(entry.path()) {
+            if let FdTarget::Socket(inode) = FdTarget::from_link(&link) {
+                inodes.push(inode);
+            }
+        }
+    }
+    Ok(inodes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linux::SysFs;
+    use std::{io, os::unix::fs::symlink};
+
+    #[test]
+    fn parses_fd_targets() {
+        assert_eq!(
+            FdTarget::from_link(Path::new("/home/user/file.txt")),
+            FdTarget::Path(PathBuf::from("/home/user/file.txt"))
+        );
+        assert_eq!(
+            FdTarget::from_link(Path::new("/dev/shm/sem.lock")),
+            FdTarget::Path(PathBuf::from("/dev/shm/sem.lock"))
+        );
+        assert_eq!(
+            FdTarget::from_link(Path::new("socket:[48213]")),
+            FdTarget::Socket(48213)
+        );
+        assert_eq!(FdTarget::from_link(Path::new("pipe:[1123]")), FdTarget::Pipe(1123));
+        assert_eq!(
+            FdTarget::from_link(Path::new("anon_inode:[eventfd]")),
+            FdTarget::AnonInode("eventfd".to_string())
+        );
+        assert_eq!(
+            FdTarget::from_link(Path::new("anon_inode:inotify")),
+            FdTarget::AnonInode("inotify".to_string())
+        );
+        assert_eq!(
+            FdTarget::from_link(Path::new("net:[4026531840]")),
+            FdTarget::Other("net:[4026531840]".to_string())
+        );
+    }
+
+    #[test]
+    fn parses_fdinfo() {
+        let info = FdInfo::from_fdinfo("pos:\t1024\nflags:\t02100002\nmnt_id:\t29\nino:\t3145\n").unwrap();
+        assert_eq!(
+            info,
+            FdInfo {
+                pos: 1024,
+                flags: 0o2100002,
+                mnt_id: 29,
+                ino: Some(3145),
+            }
+        );
+    }
+
+    #[test]
+    fn reads_file_descriptors() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let fd_p = dir.path().join("fd");
+        let fdinfo_p = dir.path().join("fdinfo");
+        fs::create_dir(&fd_p)?;
+        fs::create_dir(&fdinfo_p)?;
+
+        symlink("/dev/null", fd_p.join("0"))?;
+        symlink("socket:[48213]", fd_p.join("3"))?;
+        // fd 4 has no fdinfo as if it was closed while reading
+        symlink("pipe:[1123]", fd_p.join("4"))?;
+        // regular file like a shared memory segment in /dev/shm
+        let shm = dir.path().join("sem.lock");
+        fs::write(&shm, "")?;
+        symlink(&shm, fd_p.join("6"))?;
+        fs::write(fdinfo_p.join("6"), "pos:\t0\nflags:\t02\nmnt_id:\t31\n")?;
+        fs::write(fdinfo_p.join("0"), "pos:\t0\nflags:\t0100000\nmnt_id:\t25\n")?;
+        fs::write(fdinfo_p.join("3"), "pos:\t0\nflags:\t02\nmnt_id:\t9\n")?;
+
+        let fds = FileDescriptor::from_sys_path(&SysFs::Custom(dir.path().to_owned()).into_syspath()).unwrap();
+        assert_eq!(
+            fds,
+            vec![
+                FileDescriptor {
+                    fd: 0,
+                    target: FdTarget::Device(PathBuf::from("/dev/null")),
+                    info: FdInfo {
+                        pos: 0,
+                        flags: 0o100000,
+                        mnt_id: 25,
+                        ino: None,
+                    },
+                },
+                FileDescriptor {
+                    fd: 3,
+                    target: FdTarget::Socket(48213),
+                    info: FdInfo {
+                        pos: 0,
+                        flags: 0o2,
+                        mnt_id: 9,
+                        ino: None,
+                    },
+                },
+                FileDescriptor {
+                    fd: 6,
+                    target: FdTarget::Path(shm.clone()),
+                    info: FdInfo {
+                        pos: 0,
+                        flags: 0o2,
+                        mnt_id: 31,
+                        ino: None,
+                    },
+                },
+            ]
+        );
+
+        symlink("socket:[51000]", fd_p.join("5"))?;
+        let mut inodes = socket_inodes(&SysFs::Custom(dir.path().to_owned()).into_syspath()).unwrap();
+        inodes.sort_unstable();
+        assert_eq!(inodes, vec![48213, 51000]);
+
+        dir.close()
+    }
+}
@@ -0,0 +1,74 @@
+use crate::linux::SysPath;
+use crate::{util::trim_parse_map, Result};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents I/O statistics of a process parsed from /proc/[pid]/io
+pub struct ProcessIo {
+    /// Number of bytes read by the process using read(2) and similar syscalls, including
+    /// reads satisfied from pagecache.
+    pub rchar: u64,
+    /// Number of bytes written by the process using write(2) and similar syscalls.
+    pub wchar: u64,
+    /// Number of read I/O operations.
+    pub syscr: u64,
+    /// Number of write I/O operations.
+    pub syscw: u64,
+    /// Number of bytes this process really caused to be fetched from the storage layer.
+    pub read_bytes: u64,
+    /// Number of bytes this process caused to be sent to the storage layer.
+    pub write_bytes: u64,
+    /// Number of bytes this process caused to not happen by truncating pagecache.
+    pub cancelled_write_bytes: u64,
+}
+
+impl ProcessIo {
+    pub(crate) fn from_sys_path(path: &SysPath) -> Result<ProcessIo> {
+        ProcessIo::from_io(&path.extend("io").read()?)
+    }
+
+    pub(crate) fn from_io(io: &str) -> Result<ProcessIo> {
+        let mut pio = ProcessIo::default();
+        for line in io.lines() {
+            let mut elems = line.splitn(2, ':');
+            let key = elems.next().unwrap_or_default().trim();
+            let value = elems.next().unwrap_or_default();
+
+            match key {
+                "rchar" => pio.rchar = trim_parse_map(value)?,
+                "wchar" => pio.wchar = trim_parse_map(value)?,
+                "syscr" => pio.syscr = trim_parse_map(value)?,
+                "syscw" => pio.syscw = trim_parse_map(value)?,
+                "read_bytes" => pio.read_bytes = trim_parse_map(value)?,
+                "write_bytes" => pio.write_bytes = trim_parse_map(value)?,
+                "cancelled_write_bytes" => pio.cancelled_write_bytes = trim_parse_map(value)?,
+                _ => {}
+            }
+        }
+
+        Ok(pio)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_process_io() {
+        let io = "rchar: 323934931\nwchar: 323929600\nsyscr: 632687\nsyscw: 632675\nread_bytes: 8192\nwrite_bytes: 323932160\ncancelled_write_bytes: 4096\n";
+        let expected = ProcessIo {
+            rchar: 323_934_931,
+            wchar: 323_929_600,
+            syscr: 632_687,
+            syscw: 632_675,
+            read_bytes: 8_192,
+            write_bytes: 323_932_160,
+            cancelled_write_bytes: 4_096,
+        };
+        assert_eq!(ProcessIo::from_io(io).unwrap(), expected);
+    }
+}
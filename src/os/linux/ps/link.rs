@@ -0,0 +1,77 @@
+use crate::linux::SysPath;
+use crate::Result;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{
+    ffi::OsString,
+    os::unix::ffi::{OsStrExt, OsStringExt},
+    path::PathBuf,
+};
+
+const DELETED: &[u8] = b" (deleted)";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents a resolved `exe`, `cwd` or `root` link of a process
+pub struct LinkTarget {
+    /// Path the link points to without the ` (deleted)` suffix.
+    pub path: PathBuf,
+    /// Whether the target was removed or replaced on disk after the process opened it,
+    /// for example an executable that was upgraded while the process kept running.
+    pub deleted: bool,
+}
+
+impl LinkTarget {
+    pub(crate) fn from_sys_path(path: &SysPath, name: &str) -> Result<LinkTarget> {
+        path.extend(name).read_link().map(LinkTarget::from_link)
+    }
+
+    pub(crate) fn from_link(link: PathBuf) -> LinkTarget {
+        let bytes = link.as_os_str().as_bytes();
+        if let Some(path) = bytes.strip_suffix(DELETED) {
+            return LinkTarget {
+                path: PathBuf::from(OsString::from_vec(path.to_vec())),
+                deleted: true,
+            };
+        }
+
+        LinkTarget {
+            path: link,
+            deleted: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linux::SysFs;
+    use std::{io, os::unix::fs::symlink};
+
+    #[test]
+    fn resolves_links() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        symlink("/usr/bin/myservice (deleted)", dir.path().join("exe"))?;
+        symlink("/var/lib/myservice", dir.path().join("cwd"))?;
+        let path = SysFs::Custom(dir.path().to_owned()).into_syspath();
+
+        assert_eq!(
+            LinkTarget::from_sys_path(&path, "exe").unwrap(),
+            LinkTarget {
+                path: PathBuf::from("/usr/bin/myservice"),
+                deleted: true,
+            }
+        );
+        assert_eq!(
+            LinkTarget::from_sys_path(&path, "cwd").unwrap(),
+            LinkTarget {
+                path: PathBuf::from("/var/lib/myservice"),
+                deleted: false,
+            }
+        );
+        assert!(LinkTarget::from_sys_path(&path, "root").is_err());
+
+        dir.close()
+    }
+}
@@ -0,0 +1,365 @@
+use crate::linux::SysPath;
+use crate::{
+    util::{next, trim_parse_map},
+    Error, Result,
+};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::str::SplitN;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Access permissions of a memory region
+pub struct RegionPermissions {
+    pub read: bool,
+    pub write: bool,
+    pub execute: bool,
+    /// Whether the mapping is shared or private (copy on write)
+    pub shared: bool,
+}
+
+impl From<&str> for RegionPermissions {
+    fn from(s: &str) -> Self {
+        let perms = s.as_bytes();
+        RegionPermissions {
+            read: perms.first() == Some(&b'r'),
+            write: perms.get(1) == Some(&b'w'),
+            execute: perms.get(2) == Some(&b'x'),
+            shared: perms.get(3) == Some(&b's'),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// What a memory region is backed by
+pub enum RegionPath {
+    /// A file mapped into memory. The path may end with ` (deleted)` if the file was removed.
+    Path(String),
+    /// The process heap
+    Heap,
+    /// The main thread stack
+    Stack,
+    /// The virtual dynamically linked shared object
+    Vdso,
+    /// Variables of the vdso
+    Vvar,
+    Vsyscall,
+    /// Anonymous mapping created with mmap
+    Anonymous,
+    /// Any other pseudo-path like `[anon:name]` or `[stack:1234]`
+    Other(String),
+}
+
+impl From<&str> for RegionPath {
+    fn from(s: &str) -> Self {
+        match s {
+            "" => RegionPath::Anonymous,
+            "[heap]" => RegionPath::Heap,
+            "[stack]" => RegionPath::Stack,
+            "[vdso]" => RegionPath::Vdso,
+            "[vvar]" => RegionPath::Vvar,
+            "[vsyscall]" => RegionPath::Vsyscall,
+            s if s.starts_with('[') => RegionPath::Other(s.to_string()),
+            s => RegionPath::Path(s.to_string()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Memory usage of a region parsed from /proc/[pid]/smaps or of a whole process parsed from
+/// /proc/[pid]/smaps_rollup. All sizes are in Bytes.
+pub struct SmapsUsage {
+    /// Size of the mapping, not available in smaps_rollup.
+    pub size: u64,
+    /// Resident set size.
+    pub rss: u64,
+    /// Proportional set size, the process share of the mapping.
+    pub pss: u64,
+    pub shared_clean: u64,
+    pub shared_dirty: u64,
+    pub private_clean: u64,
+    pub private_dirty: u64,
+    /// Amount of memory currently marked as referenced or accessed.
+    pub referenced: u64,
+    /// Amount of memory that does not belong to any file.
+    pub anonymous: u64,
+    /// Amount of memory backed by transparent huge pages.
+    pub anon_huge_pages: u64,
+    /// Amount of anonymous memory that is swapped out.
+    pub swap: u64,
+    /// Proportional swap share of this mapping.
+    pub swap_pss: u64,
+    pub locked: u64,
+}
+
+impl SmapsUsage {
+    /// Sets field matching `key`, unknown keys are ignored
+    fn set(&mut self, key: &str, value: u64) {
+        match key {
+            "Size" => self.size = value,
+            "Rss" => self.rss = value,
+            "Pss" => self.pss = value,
+            "Shared_Clean" => self.shared_clean = value,
+            "Shared_Dirty" => self.shared_dirty = value,
+            "Private_Clean" => self.private_clean = value,
+            "Private_Dirty" => self.private_dirty = value,
+            "Referenced" => self.referenced = value,
+            "Anonymous" => self.anonymous = value,
+            "AnonHugePages" => self.anon_huge_pages = value,
+            "Swap" => self.swap = value,
+            "SwapPss" => self.swap_pss = value,
+            "Locked" => self.locked = value,
+            _ => {}
+        }
+    }
+
+    pub(crate) fn from_sys_path(path: &SysPath) -> Result<SmapsUsage> {
+        SmapsUsage::from_smaps_rollup(&path.extend("smaps_rollup").read()?)
+    }
+
+    pub(crate) fn from_smaps_rollup(rollup: &str) -> Result<SmapsUsage> {
+        let mut usage = SmapsUsage::default();
+        for line in rollup.lines() {
+            if let Some((key, value)) = smaps_entry(line) {
+                if value.ends_with("kB") {
+                    usage.set(key, parse_kb(value)?);
+                }
+            }
+        }
+        Ok(usage)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents a memory region of a process parsed from /proc/[pid]/maps
+pub struct MemoryRegion {
+    /// Start address of the region.
+    pub start: u64,
+    /// End address of the region (exclusive).
+    pub end: u64,
+    pub permissions: RegionPermissions,
+    /// Offset into the mapped file.
+    pub offset: u64,
+    /// Major number of the device holding the mapped file.
+    pub dev_major: u32,
+    /// Minor number of the device holding the mapped file.
+    pub dev_minor: u32,
+    /// Inode of the mapped file or 0 if there is none.
+    pub inode: u64,
+    pub path: RegionPath,
+    /// Memory usage of this region, only available when read from /proc/[pid]/smaps.
+    pub usage: Option<SmapsUsage>,
+}
+
+impl MemoryRegion {
+    /// Returns the size of this region in Bytes
+    pub fn size(&self) -> u64 {
+        self.end - self.start
+    }
+
+    pub(crate) fn from_line(line: &str) -> Result<MemoryRegion> {
+        // Fields are separated by a single space while the pathname is padded and may contain
+        // whitespace itself so it is taken as the remainder of the line.
+        let mut elems = line.splitn(6, ' ');
+
+        macro_rules! _next {
+            ($t:tt) => {
+                next::<$t, SplitN<char>>(&mut elems, &line)?
+            };
+        }
+
+        let range = _next!(String);
+        let (start, end) = split_pair(line, &range, '-')?;
+        let permissions = RegionPermissions::from(_next!(String).as_str());
+        let offset = parse_hex(line, &_next!(String))?;
+        let dev = _next!(String);
+        let (dev_major, dev_minor) = split_pair(line, &dev, ':')?;
+        let inode = _next!(u64);
+        let path = RegionPath::from(elems.next().unwrap_or_default().trim());
+
+        Ok(MemoryRegion {
+            start,
+            end,
+            permissions,
+            offset,
+            dev_major: dev_major as u32,
+            dev_minor: dev_minor as u32,
+            inode,
+            path,
+            usage: None,
+        })
+    }
+
+    pub(crate) fn from_maps(maps: &str) -> Result<Vec<MemoryRegion>> {
+        maps.lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(MemoryRegion::from_line)
+            .collect()
+    }
+
+    pub(crate) fn from_smaps(smaps: &str) -> Result<Vec<MemoryRegion>> {
+        let mut regions: Vec<MemoryRegion> = Vec::new();
+        for line in smaps.lines().filter(|l| !l.trim().is_empty()) {
+            match (smaps_entry(line), regions.last_mut()) {
+                (Some((key, value)), Some(region)) => {
+                    if value.ends_with("kB") {
+                        region
+                            .usage
+                            .get_or_insert_with(SmapsUsage::default)
+                            .set(key, parse_kb(value)?);
+                    }
+                }
+                (Some(_), None) => {
+                    return Err(Error::InvalidInputError(
+                        line.to_string(),
+                        "smaps entry without a preceding region".to_string(),
+                    ))
+                }
+                (None, _) => {
+                    let mut region = MemoryRegion::from_line(line)?;
+                    region.usage = Some(SmapsUsage::default());
+                    regions.push(region);
+                }
+            }
+        }
+        Ok(regions)
+    }
+
+    pub(crate) fn from_sys_path(path: &SysPath, extended: bool) -> Result<Vec<MemoryRegion>> {
+        if extended {
+            MemoryRegion::from_smaps(&path.extend("smaps").read()?)
+        } else {
+            MemoryRegion::from_maps(&path.extend("maps").read()?)
+        }
+    }
+}
+
+/// Returns `key` and `value` if the line is a `Key: value` entry of smaps
+fn smaps_entry(line: &str) -> Option<(&str, &str)> {
+    let mut elems = line.splitn(2, ':');
+    let key = elems.next()?;
+    if key.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((key, elems.next()?.trim()))
+}
+
+fn parse_kb(value: &str) -> Result<u64> {
+    trim_parse_map::<u64>(value.trim_end_matches("kB")).map(|v| v * 1024)
+}
+
+fn parse_hex(line: &str, value: &str) -> Result<u64> {
+    u64::from_str_radix(value, 16).map_err(|e| Error::InvalidInputError(line.to_string(), e.to_string()))
+}
+
+/// Splits `value` on `sep` parsing both parts as hexadecimal numbers
+fn split_pair(line: &str, value: &str, sep: char) -> Result<(u64, u64)> {
+    let mut elems = value.splitn(2, sep);
+    Ok((
+        parse_hex(line, elems.next().unwrap_or_default())?,
+        parse_hex(line, elems.next().unwrap_or_default())?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linux::mocks::{PROCESS_MAPS, PROCESS_SMAPS, PROCESS_SMAPS_ROLLUP};
+
+    #[test]
+    fn parses_memory_maps() {
+        let regions = MemoryRegion::from_maps(PROCESS_MAPS).unwrap();
+        assert_eq!(regions.len(), 8);
+        assert_eq!(
+            regions[0],
+            MemoryRegion {
+                start: 0x5581_1a5d_4000,
+                end: 0x5581_1a5d_6000,
+                permissions: RegionPermissions {
+                    read: true,
+                    write: false,
+                    execute: false,
+                    shared: false,
+                },
+                offset: 0,
+                dev_major: 0xfe,
+                dev_minor: 0,
+                inode: 317_783,
+                path: RegionPath::Path("/usr/bin/head".to_string()),
+                usage: None,
+            }
+        );
+        assert_eq!(regions[0].size(), 8192);
+        assert!(regions[1].permissions.execute);
+        assert_eq!(regions[2].path, RegionPath::Heap);
+        assert_eq!(regions[3].path, RegionPath::Anonymous);
+        assert_eq!(
+            regions[4].path,
+            RegionPath::Path("/usr/lib/x86_64-linux-gnu/libc.so.6 (deleted)".to_string())
+        );
+        assert!(regions[5].permissions.shared);
+        assert_eq!(regions[5].path, RegionPath::Path("/dev/shm/my  segment".to_string()));
+        assert_eq!(regions[6].path, RegionPath::Stack);
+        assert_eq!(regions[7].path, RegionPath::Vdso);
+    }
+
+    #[test]
+    fn parses_smaps() {
+        let regions = MemoryRegion::from_smaps(PROCESS_SMAPS).unwrap();
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions[0].path, RegionPath::Path("/usr/bin/head".to_string()));
+        assert_eq!(
+            regions[0].usage,
+            Some(SmapsUsage {
+                size: 8 * 1024,
+                rss: 8 * 1024,
+                pss: 4 * 1024,
+                shared_clean: 8 * 1024,
+                referenced: 8 * 1024,
+                ..Default::default()
+            })
+        );
+        assert_eq!(regions[1].path, RegionPath::Heap);
+        assert_eq!(
+            regions[1].usage,
+            Some(SmapsUsage {
+                size: 132 * 1024,
+                rss: 12 * 1024,
+                pss: 12 * 1024,
+                private_dirty: 12 * 1024,
+                referenced: 12 * 1024,
+                anonymous: 12 * 1024,
+                swap: 4 * 1024,
+                swap_pss: 4 * 1024,
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn parses_smaps_rollup() {
+        let usage = SmapsUsage::from_smaps_rollup(PROCESS_SMAPS_ROLLUP).unwrap();
+        assert_eq!(
+            usage,
+            SmapsUsage {
+                size: 0,
+                rss: 1_296 * 1024,
+                pss: 484 * 1024,
+                shared_clean: 1_112 * 1024,
+                shared_dirty: 0,
+                private_clean: 84 * 1024,
+                private_dirty: 100 * 1024,
+                referenced: 1_296 * 1024,
+                anonymous: 100 * 1024,
+                anon_huge_pages: 0,
+                swap: 0,
+                swap_pss: 0,
+                locked: 0,
+            }
+        );
+    }
+}
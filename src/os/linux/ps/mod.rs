@@ -1,17 +1,31 @@
 //! All about processes
 
+mod fd;
+mod io;
+mod link;
+mod maps;
 mod process;
+mod sampler;
 mod stat;
 mod state;
+mod status;
+mod tree;
 
+pub use fd::*;
+pub use io::*;
+pub use link::*;
+pub use maps::*;
 pub use process::*;
+pub use sampler::*;
 pub use stat::*;
 pub use state::*;
+pub use status::*;
+pub use tree::*;
 
 use crate::linux::{SysFs, SysPath};
 use crate::{Error, Result};
 
-use std::fs;
+use std::{ffi::OsString, os::unix::ffi::OsStringExt};
 
 //################################################################################
 // Public
@@ -22,17 +36,69 @@ pub fn stat_process(pid: i32) -> Result<ProcessStat> {
     ProcessStat::from_stat(&SysFs::Proc.join(pid.to_string()).join("stat").read()?)
 }
 
+/// Returns detailed Process status parsed from /proc/[pid]/status
+pub fn status_process(pid: i32) -> Result<ProcessStatus> {
+    ProcessStatus::from_sys_path(&SysFs::Proc.join(pid.to_string()))
+}
+
+/// Returns I/O statistics of a process parsed from /proc/[pid]/io
+pub fn io_process(pid: i32) -> Result<ProcessIo> {
+    ProcessIo::from_sys_path(&SysFs::Proc.join(pid.to_string()))
+}
+
+/// Returns memory regions of a process parsed from /proc/[pid]/maps
+pub fn maps_process(pid: i32) -> Result<Vec<MemoryRegion>> {
+    MemoryRegion::from_sys_path(&SysFs::Proc.join(pid.to_string()), false)
+}
+
+/// Returns memory regions of a process together with their memory usage parsed from
+/// /proc/[pid]/smaps
+pub fn smaps_process(pid: i32) -> Result<Vec<MemoryRegion>> {
+    MemoryRegion::from_sys_path(&SysFs::Proc.join(pid.to_string()), true)
+}
+
+/// Returns memory usage summed over all regions of a process parsed from /proc/[pid]/smaps_rollup
+pub fn smaps_rollup_process(pid: i32) -> Result<SmapsUsage> {
+    SmapsUsage::from_sys_path(&SysFs::Proc.join(pid.to_string()))
+}
+
+/// Returns open file descriptors of a process read from /proc/[pid]/fd and /proc/[pid]/fdinfo
+pub fn fds_process(pid: i32) -> Result<Vec<FileDescriptor>> {
+    FileDescriptor::from_sys_path(&SysFs::Proc.join(pid.to_string()))
+}
+
+/// Returns command line arguments of a process read from /proc/[pid]/cmdline
+pub fn argv_process(pid: i32) -> Result<Vec<OsString>> {
+    argv(&SysFs::Proc.join(pid.to_string()))
+}
+
+/// Returns environment variables of a process read from /proc/[pid]/environ
+pub fn environ_process(pid: i32) -> Result<Vec<(OsString, OsString)>> {
+    environ(&SysFs::Proc.join(pid.to_string()))
+}
+
+/// Returns the executable of a process resolved from /proc/[pid]/exe
+pub fn exe_process(pid: i32) -> Result<LinkTarget> {
+    LinkTarget::from_sys_path(&SysFs::Proc.join(pid.to_string()), "exe")
+}
+
+/// Returns the current working directory of a process resolved from /proc/[pid]/cwd
+pub fn cwd_process(pid: i32) -> Result<LinkTarget> {
+    LinkTarget::from_sys_path(&SysFs::Proc.join(pid.to_string()), "cwd")
+}
+
+/// Returns the root directory of a process resolved from /proc/[pid]/root
+pub fn root_process(pid: i32) -> Result<LinkTarget> {
+    LinkTarget::from_sys_path(&SysFs::Proc.join(pid.to_string()), "root")
+}
+
 /// Returns a list of pids read from /proc
 pub fn pids() -> Result<Vec<i32>> {
-    let path = SysFs::Proc.into_syspath().into_pathbuf();
     let mut pids = Vec::new();
-    for entry in fs::read_dir(&path)
-        .map_err(|e| Error::FileReadError(path.to_string_lossy().to_string(), e.to_string()))?
-        .flatten()
-    {
+    for entry in SysFs::Proc.into_syspath().read_dir()?.flatten() {
         let filename = entry.file_name();
         let sfilename = filename.as_os_str().to_string_lossy();
-        if sfilename.chars().all(|c| c.is_digit(10)) {
+        if sfilename.chars().all(|c| c.is_ascii_digit()) {
             pids.push(
                 sfilename
                     .parse::<i32>()
@@ -43,16 +109,27 @@ pub fn pids() -> Result<Vec<i32>> {
     Ok(pids)
 }
 
-/// Returns all processes currently seen in /proc parsed as Processes
+/// Returns all processes currently seen in /proc parsed as Processes. Processes that exit
+/// while being read are skipped.
 pub fn processes() -> Result<Processes> {
     let mut ps = Vec::new();
     for pid in pids()? {
-        ps.push(Process::new(pid)?);
+        match Process::new(pid) {
+            Ok(p) => ps.push(p),
+            // process exited in the meantime
+            Err(Error::FileReadError(_, _)) => continue,
+            Err(e) => return Err(e),
+        }
     }
 
     Ok(ps)
 }
 
+/// Returns a ProcessTree built from a single scan of processes in /proc
+pub fn process_tree() -> Result<ProcessTree> {
+    processes().map(ProcessTree::new)
+}
+
 //################################################################################
 // Internal
 //################################################################################
@@ -63,9 +140,44 @@ fn cmdline(path: &SysPath) -> Result<String> {
         .map(|s| s.trim_end_matches('\x00').replace('\x00', " "))
 }
 
+pub(crate) fn argv(path: &SysPath) -> Result<Vec<OsString>> {
+    path.extend("cmdline").read_bytes().map(|b| split_nul(&b))
+}
+
+pub(crate) fn environ(path: &SysPath) -> Result<Vec<(OsString, OsString)>> {
+    path.extend("environ").read_bytes().map(|b| {
+        split_nul(&b)
+            .into_iter()
+            .map(|var| {
+                let mut var = var.into_vec();
+                match var.iter().position(|&c| c == b'=') {
+                    Some(idx) => {
+                        let value = var.split_off(idx + 1);
+                        var.pop();
+                        (OsString::from_vec(var), OsString::from_vec(value))
+                    }
+                    None => (OsString::from_vec(var), OsString::new()),
+                }
+            })
+            .collect()
+    })
+}
+
+/// Splits NUL separated and terminated data into separate strings
+fn split_nul(data: &[u8]) -> Vec<OsString> {
+    let data = data.strip_suffix(b"\x00").unwrap_or(data);
+    if data.is_empty() {
+        return Vec::new();
+    }
+    data.split(|&c| c == 0)
+        .map(|s| OsString::from_vec(s.to_vec()))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::linux::{with_roots, SysRoots};
     use std::{fs, io};
     #[test]
     fn parses_cmdline() -> io::Result<()> {
@@ -83,4 +195,67 @@ mod tests {
 
         dir.close()
     }
+
+    #[test]
+    fn parses_argv() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(
+            dir.path().join("cmdline"),
+            b"/bin/sh\x00-c\x00echo 'a b'\x00\xff\xfe\x00\x00",
+        )?;
+
+        assert_eq!(
+            argv(&SysFs::Custom(dir.path().to_owned()).into_syspath()).unwrap(),
+            vec![
+                OsString::from("/bin/sh"),
+                OsString::from("-c"),
+                OsString::from("echo 'a b'"),
+                OsString::from_vec(vec![0xff, 0xfe]),
+                OsString::new(),
+            ]
+        );
+
+        fs::write(dir.path().join("cmdline"), b"")?;
+        assert!(argv(&SysFs::Custom(dir.path().to_owned()).into_syspath())
+            .unwrap()
+            .is_empty());
+
+        dir.close()
+    }
+
+    #[test]
+    fn parses_environ() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(
+            dir.path().join("environ"),
+            b"HOME=/home/user\x00OPTS=a=b c\x00EMPTY=\x00BROKEN\x00",
+        )?;
+
+        assert_eq!(
+            environ(&SysFs::Custom(dir.path().to_owned()).into_syspath()).unwrap(),
+            vec![
+                (OsString::from("HOME"), OsString::from("/home/user")),
+                (OsString::from("OPTS"), OsString::from("a=b c")),
+                (OsString::from("EMPTY"), OsString::new()),
+                (OsString::from("BROKEN"), OsString::new()),
+            ]
+        );
+
+        dir.close()
+    }
+
+    #[test]
+    fn reads_pids_from_custom_root() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        for entry in &["1", "42", "self", "cpuinfo"] {
+            fs::create_dir(dir.path().join(entry))?;
+        }
+
+        let roots = SysRoots::new(dir.path(), "/sys", "/dev");
+        let mut pids = with_roots(&roots, pids).unwrap();
+        pids.sort_unstable();
+        assert_eq!(pids, vec![1, 42]);
+
+        dir.close()
+    }
 }
@@ -1,7 +1,11 @@
-use crate::linux::ps::{cmdline, ProcessStat};
+use crate::linux::ps::{
+    argv, cmdline, environ, FileDescriptor, LinkTarget, MemoryRegion, ProcessIo, ProcessStat, ProcessStatus, SmapsUsage,
+};
 use crate::linux::{SysFs, SysPath};
 use crate::Result;
 
+use std::ffi::OsString;
+
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
 
@@ -39,16 +43,79 @@ impl Process {
         })
     }
 
+    /// Returns command line arguments of this process read from /proc/[pid]/cmdline. Unlike
+    /// `cmdline` this keeps boundaries between arguments and non UTF-8 data intact.
+    pub fn argv(&self) -> Result<Vec<OsString>> {
+        argv(&self.path())
+    }
+
+    /// Returns environment variables of this process as key/value pairs in the order they appear
+    /// in /proc/[pid]/environ. Reading the environment of processes owned by other users
+    /// requires privileges and otherwise fails with `Error::PermissionDenied`.
+    pub fn environ(&self) -> Result<Vec<(OsString, OsString)>> {
+        environ(&self.path())
+    }
+
+    /// Returns the executable of this process resolved from /proc/[pid]/exe. The target is
+    /// marked as deleted if the binary was removed or replaced on disk since the process started.
+    pub fn exe(&self) -> Result<LinkTarget> {
+        LinkTarget::from_sys_path(&self.path(), "exe")
+    }
+
+    /// Returns the current working directory of this process resolved from /proc/[pid]/cwd
+    pub fn cwd(&self) -> Result<LinkTarget> {
+        LinkTarget::from_sys_path(&self.path(), "cwd")
+    }
+
+    /// Returns the root directory of this process resolved from /proc/[pid]/root
+    pub fn root(&self) -> Result<LinkTarget> {
+        LinkTarget::from_sys_path(&self.path(), "root")
+    }
+
+    /// Returns the status of this process parsed from /proc/[pid]/status
+    pub fn status(&self) -> Result<ProcessStatus> {
+        ProcessStatus::from_sys_path(&self.path())
+    }
+
+    /// Returns I/O statistics of this process parsed from /proc/[pid]/io. Reading statistics of
+    /// processes owned by other users requires privileges and otherwise fails with
+    /// `Error::PermissionDenied`.
+    pub fn io(&self) -> Result<ProcessIo> {
+        ProcessIo::from_sys_path(&self.path())
+    }
+
+    /// Returns memory regions of this process parsed from /proc/[pid]/maps
+    pub fn memory_maps(&self) -> Result<Vec<MemoryRegion>> {
+        MemoryRegion::from_sys_path(&self.path(), false)
+    }
+
+    /// Returns memory regions of this process with their usage filled in from /proc/[pid]/smaps.
+    /// Reading smaps is considerably slower than reading maps.
+    pub fn memory_maps_extended(&self) -> Result<Vec<MemoryRegion>> {
+        MemoryRegion::from_sys_path(&self.path(), true)
+    }
+
+    /// Returns memory usage summed over all regions of this process parsed from
+    /// /proc/[pid]/smaps_rollup
+    pub fn smaps_rollup(&self) -> Result<SmapsUsage> {
+        SmapsUsage::from_sys_path(&self.path())
+    }
+
+    /// Returns open file descriptors of this process read from /proc/[pid]/fd and
+    /// /proc/[pid]/fdinfo sorted by descriptor number. Descriptors closed while reading are skipped.
+    pub fn fds(&self) -> Result<Vec<FileDescriptor>> {
+        FileDescriptor::from_sys_path(&self.path())
+    }
+
     pub fn tasks(&self) -> Result<Vec<Task>> {
         let mut tasks = Vec::new();
-        for entry in SysFs::Proc
-            .join(self.stat.pid.to_string())
-            .join("task")
-            .read_dir()?
-            .flatten()
-        {
+        for entry in self.path().join("task").read_dir()?.flatten() {
             tasks.push(Task::from_sys_path(&SysFs::Custom(entry.path()).into_syspath())?);
         }
         Ok(tasks)
     }
+
+    fn path(&self) -> SysPath {
+        SysFs::Proc.join(self.stat.pid.to_string())
+    }
 }
@@ -0,0 +1,213 @@
+use crate::linux::ps::{pids, ProcessIo, ProcessStat};
+use crate::linux::{clock_tick, SysFs};
+use crate::{Error, Result};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+/// Clock ticks per second used when the value can't be determined with sysconf
+const DEFAULT_CLOCK_TICK: i64 = 100;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents activity of a process in a single sampling interval
+pub struct ProcessRates {
+    pub pid: i32,
+    /// Percentage of a single cpu used by the process. Multithreaded processes can exceed 100.
+    pub cpu: f64,
+    /// Minor page faults per second.
+    pub minflt: f64,
+    /// Major page faults per second.
+    pub majflt: f64,
+    /// Bytes per second fetched from the storage layer or None if /proc/[pid]/io is not readable.
+    pub read_bytes: Option<f64>,
+    /// Bytes per second sent to the storage layer or None if /proc/[pid]/io is not readable.
+    pub write_bytes: Option<f64>,
+}
+
+#[derive(Clone, Debug)]
+struct Snapshot {
+    starttime: u64,
+    cpu_ticks: u64,
+    minflt: u64,
+    majflt: u64,
+    io: Option<(u64, u64)>,
+    at: Instant,
+}
+
+impl Snapshot {
+    fn new(stat: &ProcessStat, io: Option<&ProcessIo>, at: Instant) -> Snapshot {
+        Snapshot {
+            starttime: stat.starttime,
+            cpu_ticks: stat.utime + stat.stime,
+            minflt: stat.minflt,
+            majflt: stat.majflt,
+            io: io.map(|io| (io.read_bytes, io.write_bytes)),
+            at,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+/// Tracks processes between samples to compute cpu usage, page fault rate and I/O rate of
+/// each of them, similar to what `top` displays.
+///
+/// Every sample remembers statistics of each pid. A pid whose start time changed between samples
+/// was reused by a new process and is reported starting with the next sample.
+pub struct ProcessSampler {
+    prev: HashMap<i32, Snapshot>,
+    clock_tick: f64,
+}
+
+impl ProcessSampler {
+    /// Creates a new sampler taking the initial reading of all processes
+    pub fn new() -> Result<ProcessSampler> {
+        let mut sampler = ProcessSampler {
+            prev: HashMap::new(),
+            clock_tick: clock_tick()?.unwrap_or(DEFAULT_CLOCK_TICK) as f64,
+        };
+        sampler.sample()?;
+        Ok(sampler)
+    }
+
+    /// Returns rates of all processes seen both in this and the previous sample
+    pub fn sample(&mut self) -> Result<Vec<ProcessRates>> {
+        let mut readings = Vec::new();
+        for pid in pids()? {
+            let path = SysFs::Proc.join(pid.to_string());
+            let stat = match ProcessStat::from_sys_path(&path) {
+                Ok(stat) => stat,
+                // process exited in the meantime
+                Err(Error::FileReadError(_, _)) => continue,
+                Err(e) => return Err(e),
+            };
+            let io = ProcessIo::from_sys_path(&path).ok();
+            readings.push((stat, io));
+        }
+
+        Ok(self.update(readings, Instant::now()))
+    }
+
+    fn update(&mut self, readings: Vec<(ProcessStat, Option<ProcessIo>)>, now: Instant) -> Vec<ProcessRates> {
+        let mut rates = Vec::new();
+        let mut current = HashMap::with_capacity(readings.len());
+
+        for (stat, io) in readings {
+            let snapshot = Snapshot::new(&stat, io.as_ref(), now);
+            if let Some(prev) = self.prev.get(&stat.pid) {
+                if prev.starttime == snapshot.starttime {
+                    rates.push(self.rates(stat.pid, prev, &snapshot));
+                }
+            }
+            current.insert(stat.pid, snapshot);
+        }
+
+        self.prev = current;
+        rates.sort_by_key(|r| r.pid);
+        rates
+    }
+
+    fn rates(&self, pid: i32, prev: &Snapshot, cur: &Snapshot) -> ProcessRates {
+        let elapsed = cur.at.saturating_duration_since(prev.at);
+        if elapsed == Duration::from_secs(0) {
+            return ProcessRates {
+                pid,
+                ..Default::default()
+            };
+        }
+        let secs = elapsed.as_secs_f64();
+        let rate = |prev: u64, cur: u64| cur.saturating_sub(prev) as f64 / secs;
+        let io = match (prev.io, cur.io) {
+            (Some(prev), Some(cur)) => Some((rate(prev.0, cur.0), rate(prev.1, cur.1))),
+            _ => None,
+        };
+
+        ProcessRates {
+            pid,
+            cpu: rate(prev.cpu_ticks, cur.cpu_ticks) / self.clock_tick * 100.,
+            minflt: rate(prev.minflt, cur.minflt),
+            majflt: rate(prev.majflt, cur.majflt),
+            read_bytes: io.map(|io| io.0),
+            write_bytes: io.map(|io| io.1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linux::mocks::PROCESS_STAT;
+
+    fn stat(pid: i32, starttime: u64, utime: u64, minflt: u64) -> ProcessStat {
+        let mut stat = ProcessStat::from_stat(PROCESS_STAT).unwrap();
+        stat.pid = pid;
+        stat.starttime = starttime;
+        stat.utime = utime;
+        stat.stime = 0;
+        stat.minflt = minflt;
+        stat.majflt = 0;
+        stat
+    }
+
+    fn io(read_bytes: u64, write_bytes: u64) -> Option<ProcessIo> {
+        Some(ProcessIo {
+            read_bytes,
+            write_bytes,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn computes_process_rates() {
+        let mut sampler = ProcessSampler {
+            prev: HashMap::new(),
+            clock_tick: 100.,
+        };
+        let start = Instant::now();
+
+        let first = sampler.update(
+            vec![(stat(1, 10, 100, 1000), io(0, 0)), (stat(2, 20, 0, 0), None)],
+            start,
+        );
+        assert!(first.is_empty());
+
+        let second = sampler.update(
+            vec![
+                (stat(1, 10, 300, 1500), io(4096, 8192)),
+                // pid 2 was reused by a new process
+                (stat(2, 25, 50, 10), None),
+                (stat(3, 30, 0, 0), None),
+            ],
+            start + Duration::from_secs(2),
+        );
+        assert_eq!(
+            second,
+            vec![ProcessRates {
+                pid: 1,
+                cpu: 100.,
+                minflt: 250.,
+                majflt: 0.,
+                read_bytes: Some(2048.),
+                write_bytes: Some(4096.),
+            }]
+        );
+
+        let third = sampler.update(vec![(stat(2, 25, 100, 10), None)], start + Duration::from_secs(3));
+        assert_eq!(
+            third,
+            vec![ProcessRates {
+                pid: 2,
+                cpu: 50.,
+                minflt: 0.,
+                majflt: 0.,
+                read_bytes: None,
+                write_bytes: None,
+            }]
+        );
+        assert_eq!(sampler.prev.len(), 1);
+    }
+}
@@ -20,6 +20,16 @@ pub struct ProcessStat {
     pub pgrp: i32,
     pub session: i32,
     pub tty_nr: i32,
+    /// Number of minor faults the process has made which have not required loading a memory
+    /// page from disk.
+    pub minflt: u64,
+    /// Number of minor faults that the process's waited-for children have made.
+    pub cminflt: u64,
+    /// Number of major faults the process has made which have required loading a memory page
+    /// from disk.
+    pub majflt: u64,
+    /// Number of major faults that the process's waited-for children have made.
+    pub cmajflt: u64,
     pub utime: u64,
     pub stime: u64,
     pub cutime: i64,
@@ -50,6 +60,10 @@ impl ProcessStat {
         self.pgrp = p.pgrp;
         self.session = p.session;
         self.tty_nr = p.tty_nr;
+        self.minflt = p.minflt;
+        self.cminflt = p.cminflt;
+        self.majflt = p.majflt;
+        self.cmajflt = p.cmajflt;
         self.utime = p.utime;
         self.stime = p.stime;
         self.cutime = p.cutime;
@@ -96,7 +110,11 @@ impl ProcessStat {
             pgrp: _next!(i32),
             session: _next!(i32),
             tty_nr: _next!(i32),
-            utime: next::<u64, SplitAsciiWhitespace>(skip(6, &mut elems), stat)?,
+            minflt: next::<u64, SplitAsciiWhitespace>(skip(2, &mut elems), stat)?,
+            cminflt: _next!(u64),
+            majflt: _next!(u64),
+            cmajflt: _next!(u64),
+            utime: _next!(u64),
             stime: _next!(u64),
             cutime: _next!(i64),
             cstime: _next!(i64),
@@ -136,6 +154,10 @@ mod tests {
             pgrp: 69035,
             session: 69035,
             tty_nr: 0,
+            minflt: 32394,
+            cminflt: 0,
+            majflt: 1,
+            cmajflt: 0,
             utime: 3977,
             stime: 293,
             cutime: 0,
@@ -167,6 +189,10 @@ mod tests {
             pgrp: 1483,
             session: 1483,
             tty_nr: 0,
+            minflt: 1521,
+            cminflt: 252,
+            majflt: 0,
+            cmajflt: 0,
             utime: 440,
             stime: 132,
             cutime: 0,
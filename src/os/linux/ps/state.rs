@@ -1,7 +1,7 @@
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 pub enum ProcessState {
     Running,
@@ -15,6 +15,7 @@ pub enum ProcessState {
     Waking,
     Parked,
     Idle,
+    #[default]
     Unknown,
 }
 
@@ -0,0 +1,333 @@
+use crate::linux::ps::ProcessState;
+use crate::linux::{SysFs, SysPath};
+use crate::{util::trim_parse_map, Error, Result};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Real, effective, saved set and filesystem ids of a process
+pub struct Ids {
+    pub real: u32,
+    pub effective: u32,
+    pub saved: u32,
+    pub filesystem: u32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Seccomp mode of a process
+pub enum SeccompMode {
+    Disabled,
+    Strict,
+    Filter,
+    #[default]
+    Unknown,
+}
+
+impl From<&str> for SeccompMode {
+    fn from(s: &str) -> Self {
+        match s.trim() {
+            "0" => SeccompMode::Disabled,
+            "1" => SeccompMode::Strict,
+            "2" => SeccompMode::Filter,
+            _ => SeccompMode::Unknown,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents a process status parsed from /proc/[pid]/status. All memory sizes are in Bytes,
+/// fields missing for a process (like memory of kernel threads) are left as 0.
+pub struct ProcessStatus {
+    pub name: String,
+    /// Process umask, not available on kernels older than 4.7.
+    pub umask: Option<u32>,
+    pub state: ProcessState,
+    /// Thread group id.
+    pub tgid: i32,
+    /// NUMA group id.
+    pub ngid: i32,
+    pub pid: i32,
+    pub ppid: i32,
+    /// Pid of the process tracing this process or 0 if not traced.
+    pub tracer_pid: i32,
+    pub uid: Ids,
+    pub gid: Ids,
+    /// Number of file descriptor slots currently allocated.
+    pub fd_size: u32,
+    /// Supplementary group list.
+    pub groups: Vec<u32>,
+    /// Thread group ids in each of the pid namespaces the process is a member of.
+    pub ns_tgid: Vec<i32>,
+    /// Thread ids in each of the pid namespaces the process is a member of.
+    pub ns_pid: Vec<i32>,
+    /// Process group ids in each of the pid namespaces the process is a member of.
+    pub ns_pgid: Vec<i32>,
+    /// Session ids in each of the pid namespaces the process is a member of.
+    pub ns_sid: Vec<i32>,
+    /// Peak virtual memory size.
+    pub vm_peak: u64,
+    /// Virtual memory size.
+    pub vm_size: u64,
+    /// Locked memory size.
+    pub vm_lck: u64,
+    /// Pinned memory size.
+    pub vm_pin: u64,
+    /// Peak resident set size ("high water mark").
+    pub vm_hwm: u64,
+    /// Resident set size, sum of `rss_anon`, `rss_file` and `rss_shmem`.
+    pub vm_rss: u64,
+    /// Size of resident anonymous memory.
+    pub rss_anon: u64,
+    /// Size of resident file mappings.
+    pub rss_file: u64,
+    /// Size of resident shared memory.
+    pub rss_shmem: u64,
+    /// Size of data segment.
+    pub vm_data: u64,
+    /// Size of stack segment.
+    pub vm_stk: u64,
+    /// Size of text segment.
+    pub vm_exe: u64,
+    /// Shared library code size.
+    pub vm_lib: u64,
+    /// Page table entries size.
+    pub vm_pte: u64,
+    /// Swapped-out virtual memory size by anonymous private pages.
+    pub vm_swap: u64,
+    /// Size of hugetlb memory portions.
+    pub hugetlb_pages: u64,
+    /// Number of threads in process containing this thread.
+    pub threads: u32,
+    /// Number of currently queued signals for this real user id.
+    pub sig_queued: u64,
+    /// Resource limit on the number of queued signals for this process.
+    pub sig_queue_limit: u64,
+    /// Mask of signals pending for thread.
+    pub sig_pnd: u64,
+    /// Mask of signals pending for process as a whole.
+    pub shd_pnd: u64,
+    /// Mask of blocked signals.
+    pub sig_blk: u64,
+    /// Mask of ignored signals.
+    pub sig_ign: u64,
+    /// Mask of caught signals.
+    pub sig_cgt: u64,
+    /// Mask of capabilities enabled in inheritable set.
+    pub cap_inh: u64,
+    /// Mask of capabilities enabled in permitted set.
+    pub cap_prm: u64,
+    /// Mask of capabilities enabled in effective set.
+    pub cap_eff: u64,
+    /// Capability bounding set.
+    pub cap_bnd: u64,
+    /// Ambient capability set.
+    pub cap_amb: u64,
+    pub no_new_privs: bool,
+    pub seccomp: SeccompMode,
+    /// Number of voluntary context switches.
+    pub voluntary_ctxt_switches: u64,
+    /// Number of involuntary context switches.
+    pub nonvoluntary_ctxt_switches: u64,
+}
+
+impl ProcessStatus {
+    /// Rereads the current status of the process represented by this structure
+    pub fn update(&mut self) -> Result<()> {
+        *self = ProcessStatus::from_sys_path(&SysFs::Proc.join(self.pid.to_string()))?;
+        Ok(())
+    }
+
+    pub(crate) fn from_sys_path(path: &SysPath) -> Result<ProcessStatus> {
+        ProcessStatus::from_status(&path.extend("status").read()?)
+    }
+
+    pub(crate) fn from_status(status: &str) -> Result<ProcessStatus> {
+        let mut ps = ProcessStatus::default();
+        for line in status.lines() {
+            let mut elems = line.splitn(2, ':');
+            let key = elems.next().unwrap_or_default().trim();
+            let value = elems.next().unwrap_or_default().trim();
+
+            match key {
+                "Name" => ps.name = value.to_string(),
+                "Umask" => {
+                    ps.umask = Some(
+                        u32::from_str_radix(value, 8)
+                            .map_err(|e| Error::InvalidInputError(value.to_string(), e.to_string()))?,
+                    )
+                }
+                "State" => ps.state = ProcessState::from(value),
+                "Tgid" => ps.tgid = trim_parse_map(value)?,
+                "Ngid" => ps.ngid = trim_parse_map(value)?,
+                "Pid" => ps.pid = trim_parse_map(value)?,
+                "PPid" => ps.ppid = trim_parse_map(value)?,
+                "TracerPid" => ps.tracer_pid = trim_parse_map(value)?,
+                "Uid" => ps.uid = parse_ids(value)?,
+                "Gid" => ps.gid = parse_ids(value)?,
+                "FDSize" => ps.fd_size = trim_parse_map(value)?,
+                "Groups" => ps.groups = parse_list(value)?,
+                "NStgid" => ps.ns_tgid = parse_list(value)?,
+                "NSpid" => ps.ns_pid = parse_list(value)?,
+                "NSpgid" => ps.ns_pgid = parse_list(value)?,
+                "NSsid" => ps.ns_sid = parse_list(value)?,
+                "VmPeak" => ps.vm_peak = parse_kb(value)?,
+                "VmSize" => ps.vm_size = parse_kb(value)?,
+                "VmLck" => ps.vm_lck = parse_kb(value)?,
+                "VmPin" => ps.vm_pin = parse_kb(value)?,
+                "VmHWM" => ps.vm_hwm = parse_kb(value)?,
+                "VmRSS" => ps.vm_rss = parse_kb(value)?,
+                "RssAnon" => ps.rss_anon = parse_kb(value)?,
+                "RssFile" => ps.rss_file = parse_kb(value)?,
+                "RssShmem" => ps.rss_shmem = parse_kb(value)?,
+                "VmData" => ps.vm_data = parse_kb(value)?,
+                "VmStk" => ps.vm_stk = parse_kb(value)?,
+                "VmExe" => ps.vm_exe = parse_kb(value)?,
+                "VmLib" => ps.vm_lib = parse_kb(value)?,
+                "VmPTE" => ps.vm_pte = parse_kb(value)?,
+                "VmSwap" => ps.vm_swap = parse_kb(value)?,
+                "HugetlbPages" => ps.hugetlb_pages = parse_kb(value)?,
+                "Threads" => ps.threads = trim_parse_map(value)?,
+                "SigQ" => {
+                    let mut elems = value.splitn(2, '/');
+                    ps.sig_queued = trim_parse_map(elems.next().unwrap_or_default())?;
+                    ps.sig_queue_limit = trim_parse_map(elems.next().unwrap_or_default())?;
+                }
+                "SigPnd" => ps.sig_pnd = parse_hex(value)?,
+                "ShdPnd" => ps.shd_pnd = parse_hex(value)?,
+                "SigBlk" => ps.sig_blk = parse_hex(value)?,
+                "SigIgn" => ps.sig_ign = parse_hex(value)?,
+                "SigCgt" => ps.sig_cgt = parse_hex(value)?,
+                "CapInh" => ps.cap_inh = parse_hex(value)?,
+                "CapPrm" => ps.cap_prm = parse_hex(value)?,
+                "CapEff" => ps.cap_eff = parse_hex(value)?,
+                "CapBnd" => ps.cap_bnd = parse_hex(value)?,
+                "CapAmb" => ps.cap_amb = parse_hex(value)?,
+                "NoNewPrivs" => ps.no_new_privs = value == "1",
+                "Seccomp" => ps.seccomp = SeccompMode::from(value),
+                "voluntary_ctxt_switches" => ps.voluntary_ctxt_switches = trim_parse_map(value)?,
+                "nonvoluntary_ctxt_switches" => ps.nonvoluntary_ctxt_switches = trim_parse_map(value)?,
+                _ => {}
+            }
+        }
+
+        Ok(ps)
+    }
+}
+
+fn parse_ids(value: &str) -> Result<Ids> {
+    let ids = parse_list::<u32>(value)?;
+    if ids.len() != 4 {
+        return Err(Error::InvalidInputError(
+            value.to_string(),
+            "expected real, effective, saved set and filesystem ids".to_string(),
+        ));
+    }
+    Ok(Ids {
+        real: ids[0],
+        effective: ids[1],
+        saved: ids[2],
+        filesystem: ids[3],
+    })
+}
+
+fn parse_list<T>(value: &str) -> Result<Vec<T>>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    value.split_ascii_whitespace().map(trim_parse_map::<T>).collect()
+}
+
+fn parse_kb(value: &str) -> Result<u64> {
+    trim_parse_map::<u64>(value.trim_end_matches("kB")).map(|v| v * 1024)
+}
+
+fn parse_hex(value: &str) -> Result<u64> {
+    u64::from_str_radix(value.trim(), 16).map_err(|e| Error::InvalidInputError(value.to_string(), e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linux::mocks::PROCESS_STATUS;
+
+    #[test]
+    fn parses_process_status() {
+        let status = ProcessStatus {
+            name: "containerd-shim".to_string(),
+            umask: Some(0o022),
+            state: ProcessState::Sleeping,
+            tgid: 4021,
+            ngid: 0,
+            pid: 4021,
+            ppid: 1,
+            tracer_pid: 0,
+            uid: Ids {
+                real: 1000,
+                effective: 1000,
+                saved: 1000,
+                filesystem: 1000,
+            },
+            gid: Ids {
+                real: 1000,
+                effective: 1000,
+                saved: 1000,
+                filesystem: 1000,
+            },
+            fd_size: 64,
+            groups: vec![4, 24, 27, 1000],
+            ns_tgid: vec![4021, 1],
+            ns_pid: vec![4021, 1],
+            ns_pgid: vec![4021, 1],
+            ns_sid: vec![3990, 0],
+            vm_peak: 1_242_304 * 1024,
+            vm_size: 1_242_176 * 1024,
+            vm_lck: 0,
+            vm_pin: 0,
+            vm_hwm: 20_800 * 1024,
+            vm_rss: 20_160 * 1024,
+            rss_anon: 9_024 * 1024,
+            rss_file: 11_136 * 1024,
+            rss_shmem: 0,
+            vm_data: 79_468 * 1024,
+            vm_stk: 132 * 1024,
+            vm_exe: 3_884 * 1024,
+            vm_lib: 2_064 * 1024,
+            vm_pte: 148 * 1024,
+            vm_swap: 512 * 1024,
+            hugetlb_pages: 0,
+            threads: 11,
+            sig_queued: 2,
+            sig_queue_limit: 63_422,
+            sig_pnd: 0,
+            shd_pnd: 0,
+            sig_blk: 0,
+            sig_ign: 0x1000,
+            sig_cgt: 0x2_fffb_feff,
+            cap_inh: 0,
+            cap_prm: 0,
+            cap_eff: 0,
+            cap_bnd: 0x1ff_ffff_ffff,
+            cap_amb: 0,
+            no_new_privs: true,
+            seccomp: SeccompMode::Filter,
+            voluntary_ctxt_switches: 1_412,
+            nonvoluntary_ctxt_switches: 37,
+        };
+        assert_eq!(ProcessStatus::from_status(PROCESS_STATUS).unwrap(), status);
+    }
+
+    #[test]
+    fn parses_kernel_thread_status() {
+        let status =
+            ProcessStatus::from_status("Name:\tkthreadd\nState:\tS (sleeping)\nPid:\t2\nThreads:\t1\n").unwrap();
+        assert_eq!(status.name, "kthreadd");
+        assert_eq!(status.umask, None);
+        assert_eq!(status.vm_rss, 0);
+        assert!(status.groups.is_empty());
+    }
+}
@@ -0,0 +1,286 @@
+use crate::linux::ps::{Process, Processes};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Resources used by a process and all of its descendants
+pub struct SubtreeUsage {
+    /// Number of processes in the subtree including its root.
+    pub processes: usize,
+    /// Time spent in user mode in clock ticks.
+    pub utime: u64,
+    /// Time spent in kernel mode in clock ticks.
+    pub stime: u64,
+    /// Resident set size in pages.
+    pub rss: u64,
+}
+
+impl SubtreeUsage {
+    /// Total cpu time spent in user and kernel mode in clock ticks
+    pub fn cpu_time(&self) -> u64 {
+        self.utime + self.stime
+    }
+}
+
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// A tree of processes built from a single scan of /proc using parent pids of each process.
+///
+/// Processes whose parent is not part of the scan, for example because the parent exited while
+/// the scan was running, are treated as roots of the tree. So is the lowest pid of any cycle of
+/// parent pids, which a scan racing with pid reuse could observe.
+pub struct ProcessTree {
+    processes: HashMap<i32, Process>,
+    children: HashMap<i32, Vec<i32>>,
+    roots: Vec<i32>,
+}
+
+impl ProcessTree {
+    /// Builds a tree out of already gathered processes
+    pub fn new(processes: Processes) -> ProcessTree {
+        let processes: HashMap<i32, Process> = processes.into_iter().map(|p| (p.stat.pid, p)).collect();
+        let mut children: HashMap<i32, Vec<i32>> = HashMap::new();
+        let mut roots = Vec::new();
+
+        for (pid, process) in processes.iter() {
+            let ppid = process.stat.ppid;
+            if ppid != *pid && processes.contains_key(&ppid) {
+                children.entry(ppid).or_default().push(*pid);
+            } else {
+                roots.push(*pid);
+            }
+        }
+
+        let mut reachable = HashSet::new();
+        for root in &roots {
+            mark_subtree(&children, *root, &mut reachable);
+        }
+        let mut unreachable: Vec<i32> = processes
+            .keys()
+            .filter(|pid| !reachable.contains(*pid))
+            .copied()
+            .collect();
+        unreachable.sort_unstable();
+        for pid in unreachable {
+            if reachable.contains(&pid) {
+                continue;
+            }
+            // every unreachable process has a parent in the scan, so following parents ends in a cycle
+            let mut path = Vec::new();
+            let mut current = pid;
+            while !path.contains(&current) {
+                path.push(current);
+                current = processes[&current].stat.ppid;
+            }
+            let cycle_start = path.iter().position(|p| *p == current).unwrap_or_default();
+            let root = path[cycle_start..].iter().copied().min().unwrap_or(current);
+
+            if let Some(siblings) = children.get_mut(&processes[&root].stat.ppid) {
+                siblings.retain(|p| *p != root);
+            }
+            roots.push(root);
+            mark_subtree(&children, root, &mut reachable);
+        }
+
+        roots.sort_unstable();
+        for pids in children.values_mut() {
+            pids.sort_unstable();
+        }
+
+        ProcessTree {
+            processes,
+            children,
+            roots,
+        }
+    }
+
+    /// Returns a process with `pid` if it's part of this tree
+    pub fn get(&self, pid: i32) -> Option<&Process> {
+        self.processes.get(&pid)
+    }
+
+    /// Returns the number of processes in this tree
+    pub fn len(&self) -> usize {
+        self.processes.len()
+    }
+
+    /// Returns true if there are no processes in this tree
+    pub fn is_empty(&self) -> bool {
+        self.processes.is_empty()
+    }
+
+    /// Returns pids of processes without a parent in this tree sorted ascending
+    pub fn roots(&self) -> &[i32] {
+        &self.roots
+    }
+
+    /// Returns pids of direct children of `pid` sorted ascending
+    pub fn children(&self, pid: i32) -> &[i32] {
+        self.children.get(&pid).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Returns pids of all descendants of `pid` in depth first order
+    pub fn descendants(&self, pid: i32) -> Vec<i32> {
+        let mut descendants = Vec::new();
+        let mut visited = HashSet::new();
+        visited.insert(pid);
+
+        let mut stack: Vec<i32> = self.children(pid).iter().rev().copied().collect();
+        while let Some(child) = stack.pop() {
+            if !visited.insert(child) {
+                continue;
+            }
+            descendants.push(child);
+            stack.extend(self.children(child).iter().rev());
+        }
+        descendants
+    }
+
+    /// Returns pids of ancestors of `pid` starting with its parent and ending with a root
+    pub fn ancestors(&self, pid: i32) -> Vec<i32> {
+        let mut ancestors = Vec::new();
+        let mut seen = HashSet::new();
+        seen.insert(pid);
+
+        let mut current = pid;
+        while let Some(process) = self.processes.get(&current) {
+            if self.roots.binary_search(&current).is_ok() {
+                break;
+            }
+            let ppid = process.stat.ppid;
+            if !self.processes.contains_key(&ppid) || !seen.insert(ppid) {
+                break;
+            }
+            ancestors.push(ppid);
+            current = ppid;
+        }
+        ancestors
+    }
+
+    /// Returns resources used by `pid` and all of its descendants or None if the process is not
+    /// part of this tree
+    pub fn subtree_usage(&self, pid: i32) -> Option<SubtreeUsage> {
+        let root = self.processes.get(&pid)?;
+        let mut usage = SubtreeUsage::default();
+        for process in std::iter::once(root).chain(self.descendants(pid).iter().filter_map(|p| self.get(*p))) {
+            usage.processes += 1;
+            usage.utime += process.stat.utime;
+            usage.stime += process.stat.stime;
+            usage.rss += process.stat.rss.max(0) as u64;
+        }
+        Some(usage)
+    }
+
+    /// Returns an iterator over all processes in this tree in arbitrary order
+    pub fn iter(&self) -> impl Iterator<Item = &Process> {
+        self.processes.values()
+    }
+}
+
+fn mark_subtree(children: &HashMap<i32, Vec<i32>>, pid: i32, reachable: &mut HashSet<i32>) {
+    let mut stack = vec![pid];
+    while let Some(pid) = stack.pop() {
+        if reachable.insert(pid) {
+            stack.extend(children.get(&pid).into_iter().flatten());
+        }
+    }
+}
+
+impl From<Processes> for ProcessTree {
+    fn from(processes: Processes) -> Self {
+        ProcessTree::new(processes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linux::ps::ProcessStat;
+
+    fn process(pid: i32, ppid: i32, utime: u64, rss: i32) -> Process {
+        let mut stat = ProcessStat::from_stat(crate::linux::mocks::PROCESS_STAT).unwrap();
+        stat.pid = pid;
+        stat.ppid = ppid;
+        stat.utime = utime;
+        stat.stime = 1;
+        stat.rss = rss;
+        Process {
+            cmdline: String::new(),
+            stat,
+        }
+    }
+
+    fn tree() -> ProcessTree {
+        //  1 ─┬─ 10 ─┬─ 100
+        //     │      └─ 101 ── 1000
+        //     └─ 11
+        //  2 ── 20
+        //  500 (parent 400 exited during scan) ── 501
+        ProcessTree::new(vec![
+            process(1, 0, 10, 100),
+            process(2, 0, 0, 0),
+            process(10, 1, 5, 10),
+            process(11, 1, 1, 1),
+            process(20, 2, 1, 1),
+            process(100, 10, 2, 20),
+            process(101, 10, 3, 30),
+            process(1000, 101, 4, 40),
+            process(500, 400, 1, 1),
+            process(501, 500, 1, 1),
+        ])
+    }
+
+    #[test]
+    fn finds_roots_and_children() {
+        let tree = tree();
+        assert_eq!(tree.len(), 10);
+        assert_eq!(tree.roots(), &[1, 2, 500]);
+        assert_eq!(tree.children(1), &[10, 11]);
+        assert_eq!(tree.children(10), &[100, 101]);
+        assert_eq!(tree.children(500), &[501]);
+        assert!(tree.children(1000).is_empty());
+        assert!(tree.children(12345).is_empty());
+    }
+
+    #[test]
+    fn traverses_tree() {
+        let tree = tree();
+        assert_eq!(tree.descendants(1), vec![10, 100, 101, 1000, 11]);
+        assert_eq!(tree.descendants(101), vec![1000]);
+        assert_eq!(tree.ancestors(1000), vec![101, 10, 1]);
+        assert_eq!(tree.ancestors(501), vec![500]);
+        assert!(tree.ancestors(1).is_empty());
+    }
+
+    #[test]
+    fn aggregates_subtree_usage() {
+        let tree = tree();
+        assert_eq!(
+            tree.subtree_usage(10),
+            Some(SubtreeUsage {
+                processes: 4,
+                utime: 14,
+                stime: 4,
+                rss: 100,
+            })
+        );
+        assert_eq!(tree.subtree_usage(10).unwrap().cpu_time(), 18);
+        assert_eq!(tree.subtree_usage(12345), None);
+    }
+
+    #[test]
+    fn breaks_ppid_cycles() {
+        // 1 and 2 are parents of each other, 3 is a child of 2
+        let tree = ProcessTree::new(vec![process(2, 1, 1, 1), process(1, 2, 1, 1), process(3, 2, 1, 1)]);
+        assert_eq!(tree.roots(), &[1]);
+        assert_eq!(tree.children(1), &[2]);
+        assert_eq!(tree.children(2), &[3]);
+        assert_eq!(tree.descendants(1), vec![2, 3]);
+        assert_eq!(tree.ancestors(3), vec![2, 1]);
+        assert!(tree.ancestors(1).is_empty());
+        assert_eq!(tree.subtree_usage(1).unwrap().processes, 3);
+    }
+}
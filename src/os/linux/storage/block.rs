@@ -0,0 +1,453 @@
+use crate::linux::{SysFs, SysPath};
+use crate::{Error, Result};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Sizes in sysfs are always reported in 512 byte sectors regardless of the block size of
+/// the device
+const SECTOR_SIZE: u64 = 512;
+
+pub type BlockDevices = Vec<BlockDevice>;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents a partition of a block device read from /sys/block/[dev]/[part]
+pub struct Partition {
+    pub name: String,
+    /// Major and minor number like `259:1`.
+    pub dev: String,
+    /// Partition number within the parent device.
+    pub number: u32,
+    /// Offset from the beginning of the parent device in bytes.
+    pub start: u64,
+    /// Size in bytes.
+    pub size: u64,
+    pub read_only: bool,
+    /// Devices built on top of this partition, for example device mapper volumes.
+    pub holders: Vec<String>,
+}
+
+impl Partition {
+    pub(crate) fn from_sys_path(path: &SysPath) -> Result<Partition> {
+        Ok(Partition {
+            name: file_name(path.as_path()),
+            dev: path.extend("dev").read()?.trim().to_string(),
+            number: path.extend("partition").read_as::<u32>()?,
+            start: path.extend("start").read_as::<u64>()? * SECTOR_SIZE,
+            size: path.extend("size").read_as::<u64>()? * SECTOR_SIZE,
+            read_only: read_flag(path, "ro"),
+            holders: dir_names(&path.extend("holders"))?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents a block device read from /sys/block/[dev]
+pub struct BlockDevice {
+    pub name: String,
+    /// Major and minor number like `259:0`.
+    pub dev: String,
+    /// Size in bytes.
+    pub size: u64,
+    /// Smallest unit the device can address in bytes.
+    pub logical_block_size: u32,
+    /// Smallest unit the device can write without a read-modify-write cycle in bytes.
+    pub physical_block_size: u32,
+    pub rotational: bool,
+    pub removable: bool,
+    pub read_only: bool,
+    pub model: Option<String>,
+    pub vendor: Option<String>,
+    pub serial: Option<String>,
+    /// Active I/O scheduler or None if the device has no request queue.
+    pub scheduler: Option<String>,
+    /// Name of the volume in /dev/mapper if this is a device mapper device.
+    pub dm_name: Option<String>,
+    pub partitions: Vec<Partition>,
+    /// Devices built on top of this device, for example device mapper volumes.
+    pub holders: Vec<String>,
+    /// Devices this device is built on, for example physical volumes of a device mapper volume.
+    pub slaves: Vec<String>,
+}
+
+impl BlockDevice {
+    pub(crate) fn from_sys_path(path: &SysPath) -> Result<BlockDevice> {
+        let name = file_name(path.as_path());
+        let queue = path.extend("queue");
+
+        let mut partitions = Vec::new();
+        for entry in path.read_dir()?.flatten() {
+            let entry = SysFs::Custom(entry.path()).into_syspath();
+            if entry.extend("partition").as_path().exists() {
+                partitions.push(Partition::from_sys_path(&entry)?);
+            }
+        }
+        partitions.sort_by_key(|part| part.number);
+
+        Ok(BlockDevice {
+            dev: path.extend("dev").read()?.trim().to_string(),
+            size: path.extend("size").read_as::<u64>()? * SECTOR_SIZE,
+            logical_block_size: queue
+                .extend("logical_block_size")
+                .read_as::<u32>()
+                .unwrap_or(SECTOR_SIZE as u32),
+            physical_block_size: queue
+                .extend("physical_block_size")
+                .read_as::<u32>()
+                .unwrap_or(SECTOR_SIZE as u32),
+            rotational: read_flag(&queue, "rotational"),
+            removable: read_flag(path, "removable"),
+            read_only: read_flag(path, "ro"),
+            model: read_attr(path, &["device/model"]),
+            vendor: read_attr(path, &["device/vendor"]),
+            // virtio disks expose the serial directly in the device directory
+            serial: read_attr(path, &["device/serial", "serial"]),
+            scheduler: queue.extend("scheduler").read().ok().and_then(|s| active_scheduler(&s)),
+            dm_name: read_attr(path, &["dm/name"]),
+            partitions,
+            holders: dir_names(&path.extend("holders"))?,
+            slaves: dir_names(&path.extend("slaves"))?,
+            name,
+        })
+    }
+
+    /// Returns a partition of this device with `name`
+    pub fn partition(&self, name: &str) -> Option<&Partition> {
+        self.partitions.iter().find(|part| part.name == name)
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+fn read_flag(path: &SysPath, name: &str) -> bool {
+    path.extend(name).read_as::<u8>().map(|v| v == 1).unwrap_or_default()
+}
+
+/// Returns the first nonempty attribute out of `names` relative to `path`
+fn read_attr(path: &SysPath, names: &[&str]) -> Option<String> {
+    names.iter().find_map(|name| {
+        path.extend(name)
+            .read()
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    })
+}
+
+/// Returns names of entries in `path` or an empty list if it doesn't exist
+fn dir_names(path: &SysPath) -> Result<Vec<String>> {
+    let entries = match path.read_dir() {
+        Ok(entries) => entries,
+        Err(Error::FileReadError(_, _)) => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let mut names: Vec<String> = entries
+        .flatten()
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+/// Parses the active scheduler out of a list like `none [mq-deadline] kyber bfq`
+fn active_scheduler(schedulers: &str) -> Option<String> {
+    let mut schedulers = schedulers.split_ascii_whitespace();
+    let first = schedulers.clone().next();
+    schedulers
+        .find_map(|s| s.strip_prefix('[').and_then(|s| s.strip_suffix(']')))
+        .or(first)
+        .map(str::to_string)
+}
+
+pub(crate) fn block_devices_from(path: &SysPath) -> Result<BlockDevices> {
+    let mut devices = Vec::new();
+    for entry in path.read_dir()?.flatten() {
+        match BlockDevice::from_sys_path(&SysFs::Custom(entry.path()).into_syspath()) {
+            Ok(dev) => devices.push(dev),
+            // device removed in the meantime
+            Err(Error::FileReadError(_, _)) => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    devices.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(devices)
+}
+
+/// Returns names of physical disks `name` is stored on by following partitions to their parent
+/// devices and device mapper volumes to their slaves. `name` can be a kernel name like `dm-0`,
+/// a partition or a device mapper name.
+pub(crate) fn trace_disks(devices: &[BlockDevice], name: &str) -> Vec<String> {
+    let mut disks = Vec::new();
+    let mut pending = vec![name.to_string()];
+    // guards against cycles in a malformed tree
+    let mut visited = Vec::new();
+
+    while let Some(name) = pending.pop() {
+        if visited.contains(&name) {
+            continue;
+        }
+        visited.push(name.clone());
+
+        if let Some(dev) = devices
+            .iter()
+            .find(|dev| dev.name == name || dev.dm_name.as_deref() == Some(name.as_str()))
+        {
+            if dev.slaves.is_empty() {
+                disks.push(dev.name.clone());
+            } else {
+                pending.extend(dev.slaves.iter().cloned());
+            }
+        } else if let Some(dev) = devices.iter().find(|dev| dev.partition(&name).is_some()) {
+            pending.push(dev.name.clone());
+        }
+    }
+
+    disks.sort();
+    disks.dedup();
+    disks
+}
+
+/// Returns all block devices read from /sys/block sorted by name
+pub fn block_devices() -> Result<BlockDevices> {
+    block_devices_from(&SysFs::Sys.join("block"))
+}
+
+/// Returns a block device with `name` read from /sys/block/[name]
+pub fn block_device(name: &str) -> Result<BlockDevice> {
+    BlockDevice::from_sys_path(&SysFs::Sys.join("block").join(name))
+}
+
+/// Returns names of physical disks backing `volume` like `/dev/mapper/vgroot-root`,
+/// `/dev/nvme0n1p1` or `dm-0`. Symbolic links in /dev are followed so volumes from
+/// `/dev/disk/by-uuid` can be traced as well.
+pub fn physical_disks(volume: &str) -> Result<Vec<String>> {
+    let name = match volume.strip_prefix("/dev/") {
+        Some(path) => match SysFs::Dev.join(path).read_link() {
+            Ok(target) => file_name(&target),
+            Err(_) => file_name(Path::new(path)),
+        },
+        None => volume.to_string(),
+    };
+    Ok(trace_disks(&block_devices()?, &name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linux::mocks::MOUNTS;
+    use std::{fs, io};
+
+    fn write_files(dir: &Path, files: &[(&str, &str)]) -> io::Result<()> {
+        for (file, content) in files {
+            let path = dir.join(file);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, content)?;
+        }
+        Ok(())
+    }
+
+    fn write_links(dir: &Path, names: &[&str]) -> io::Result<()> {
+        fs::create_dir_all(dir)?;
+        for name in names {
+            fs::create_dir_all(dir.join(name))?;
+        }
+        Ok(())
+    }
+
+    fn write_dm(root: &Path, name: &str, dm_name: &str, slaves: &[&str]) -> io::Result<()> {
+        let dir = root.join(name);
+        write_files(
+            &dir,
+            &[
+                ("dev", "254:0\n"),
+                ("size", "41943040\n"),
+                ("ro", "0\n"),
+                ("removable", "0\n"),
+                ("dm/name", &format!("{}\n", dm_name)),
+                ("queue/scheduler", "none\n"),
+                ("queue/rotational", "0\n"),
+            ],
+        )?;
+        write_links(&dir.join("holders"), &[])?;
+        write_links(&dir.join("slaves"), slaves)
+    }
+
+    fn write_tree(root: &Path) -> io::Result<()> {
+        let nvme = root.join("nvme0n1");
+        write_files(
+            &nvme,
+            &[
+                ("dev", "259:0\n"),
+                ("size", "1000215216\n"),
+                ("ro", "0\n"),
+                ("removable", "0\n"),
+                ("device/model", "Samsung SSD 970 EVO Plus 500GB           \n"),
+                ("device/serial", "S4EVNF0M123456A     \n"),
+                ("queue/scheduler", "[none] mq-deadline kyber bfq\n"),
+                ("queue/rotational", "0\n"),
+                ("queue/logical_block_size", "512\n"),
+                ("queue/physical_block_size", "512\n"),
+                ("nvme0n1p1/dev", "259:1\n"),
+                ("nvme0n1p1/partition", "1\n"),
+                ("nvme0n1p1/start", "2048\n"),
+                ("nvme0n1p1/size", "1048576\n"),
+                ("nvme0n1p1/ro", "0\n"),
+                ("nvme0n1p2/dev", "259:2\n"),
+                ("nvme0n1p2/partition", "2\n"),
+                ("nvme0n1p2/start", "1050624\n"),
+                ("nvme0n1p2/size", "999164559\n"),
+                ("nvme0n1p2/ro", "0\n"),
+            ],
+        )?;
+        write_links(&nvme.join("holders"), &[])?;
+        write_links(&nvme.join("slaves"), &[])?;
+        write_links(&nvme.join("nvme0n1p1/holders"), &[])?;
+        write_links(&nvme.join("nvme0n1p2/holders"), &["dm-0", "dm-1", "dm-2"])?;
+
+        let sda = root.join("sda");
+        write_files(
+            &sda,
+            &[
+                ("dev", "8:0\n"),
+                ("size", "7814037168\n"),
+                ("ro", "0\n"),
+                ("removable", "0\n"),
+                ("device/model", "WDC WD40EFRX-68N\n"),
+                ("device/vendor", "ATA     \n"),
+                ("queue/scheduler", "mq-deadline kyber [bfq] none\n"),
+                ("queue/rotational", "1\n"),
+                ("queue/logical_block_size", "512\n"),
+                ("queue/physical_block_size", "4096\n"),
+                ("sda1/dev", "8:1\n"),
+                ("sda1/partition", "1\n"),
+                ("sda1/start", "2048\n"),
+                ("sda1/size", "7814035087\n"),
+            ],
+        )?;
+        write_links(&sda.join("sda1/holders"), &["dm-3", "dm-4", "dm-5"])?;
+
+        let sdb = root.join("sdb");
+        write_files(
+            &sdb,
+            &[
+                ("dev", "8:16\n"),
+                ("size", "7814037168\n"),
+                ("removable", "0\n"),
+                ("queue/rotational", "1\n"),
+            ],
+        )?;
+        write_links(&sdb.join("holders"), &["dm-5"])?;
+
+        write_dm(root, "dm-0", "vgroot-root", &["nvme0n1p2"])?;
+        write_dm(root, "dm-1", "vgroot-home", &["nvme0n1p2"])?;
+        write_dm(root, "dm-2", "vgroot-var", &["nvme0n1p2"])?;
+        write_dm(root, "dm-3", "vgstor-rand", &["sda1"])?;
+        write_dm(root, "dm-4", "vgstor-docs", &["sda1"])?;
+        write_dm(root, "dm-5", "vgstor-media", &["sda1", "sdb"])
+    }
+
+    #[test]
+    fn reads_block_devices() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        write_tree(dir.path())?;
+        let devices = block_devices_from(&SysFs::Custom(dir.path().to_owned()).into_syspath()).unwrap();
+
+        assert_eq!(
+            devices.iter().map(|dev| dev.name.as_str()).collect::<Vec<_>>(),
+            vec!["dm-0", "dm-1", "dm-2", "dm-3", "dm-4", "dm-5", "nvme0n1", "sda", "sdb"]
+        );
+
+        let nvme = &devices[6];
+        assert_eq!(nvme.dev, "259:0");
+        assert_eq!(nvme.size, 512_110_190_592);
+        assert_eq!(nvme.model.as_deref(), Some("Samsung SSD 970 EVO Plus 500GB"));
+        assert_eq!(nvme.serial.as_deref(), Some("S4EVNF0M123456A"));
+        assert_eq!(nvme.vendor, None);
+        assert_eq!(nvme.scheduler.as_deref(), Some("none"));
+        assert!(!nvme.rotational);
+        assert_eq!(
+            nvme.partitions[0],
+            Partition {
+                name: "nvme0n1p1".to_string(),
+                dev: "259:1".to_string(),
+                number: 1,
+                start: 1_048_576,
+                size: 536_870_912,
+                read_only: false,
+                holders: Vec::new(),
+            }
+        );
+        assert_eq!(
+            nvme.partition("nvme0n1p2").unwrap().holders,
+            vec!["dm-0", "dm-1", "dm-2"]
+        );
+
+        let sda = &devices[7];
+        assert_eq!(sda.physical_block_size, 4096);
+        assert_eq!(sda.vendor.as_deref(), Some("ATA"));
+        assert_eq!(sda.scheduler.as_deref(), Some("bfq"));
+        assert!(sda.rotational);
+
+        let sdb = &devices[8];
+        assert_eq!(sdb.logical_block_size, 512);
+        assert_eq!(sdb.scheduler, None);
+        assert_eq!(sdb.holders, vec!["dm-5"]);
+
+        let dm = &devices[5];
+        assert_eq!(dm.dm_name.as_deref(), Some("vgstor-media"));
+        assert_eq!(dm.slaves, vec!["sda1", "sdb"]);
+        Ok(())
+    }
+
+    #[test]
+    fn traces_mounted_volumes_to_disks() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        write_tree(dir.path())?;
+        let devices = block_devices_from(&SysFs::Custom(dir.path().to_owned()).into_syspath()).unwrap();
+
+        let traced: Vec<(String, Vec<String>)> = MOUNTS
+            .lines()
+            .filter_map(|line| line.split_ascii_whitespace().next())
+            .filter_map(|volume| volume.strip_prefix("/dev/"))
+            .map(|volume| {
+                let name = file_name(Path::new(volume));
+                (name.clone(), trace_disks(&devices, &name))
+            })
+            .collect();
+
+        let disks = |names: &[&str]| names.iter().map(|name| name.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            traced,
+            vec![
+                ("vgroot-root".to_string(), disks(&["nvme0n1"])),
+                ("vgroot-home".to_string(), disks(&["nvme0n1"])),
+                ("vgroot-var".to_string(), disks(&["nvme0n1"])),
+                ("nvme0n1p1".to_string(), disks(&["nvme0n1"])),
+                ("vgstor-rand".to_string(), disks(&["sda"])),
+                ("vgstor-docs".to_string(), disks(&["sda"])),
+                ("vgstor-media".to_string(), disks(&["sda", "sdb"])),
+            ]
+        );
+        assert!(trace_disks(&devices, "sdz").is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn parses_active_scheduler() {
+        assert_eq!(
+            active_scheduler("none [mq-deadline] kyber bfq\n").as_deref(),
+            Some("mq-deadline")
+        );
+        assert_eq!(active_scheduler("none\n").as_deref(), Some("none"));
+        assert_eq!(active_scheduler(""), None);
+    }
+}
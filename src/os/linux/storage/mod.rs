@@ -0,0 +1,9 @@
+//! Block devices and their statistics
+
+mod block;
+mod sampler;
+mod stats;
+
+pub use block::*;
+pub use sampler::*;
+pub use stats::*;
@@ -0,0 +1,221 @@
+use crate::linux::storage::{disks_stats, DiskStats};
+use crate::{
+    util::{counter_delta, counter_delta_32},
+    Result,
+};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+/// Sectors in /proc/diskstats are always 512 bytes
+const SECTOR_SIZE: f64 = 512.;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents I/O activity of a block device in a single sampling interval with the same metrics
+/// as `iostat -x`
+pub struct DiskRates {
+    pub name: String,
+    /// Reads completed per second.
+    pub reads: f64,
+    /// Writes completed per second.
+    pub writes: f64,
+    /// Discards completed per second.
+    pub discards: f64,
+    /// Flushes completed per second.
+    pub flushes: f64,
+    /// Bytes read per second.
+    pub read_bytes: f64,
+    /// Bytes written per second.
+    pub write_bytes: f64,
+    /// Bytes discarded per second.
+    pub discard_bytes: f64,
+    /// Read requests merged per second.
+    pub reads_merged: f64,
+    /// Write requests merged per second.
+    pub writes_merged: f64,
+    /// Average time in milliseconds to complete a read including the time spent in queue.
+    pub read_await: f64,
+    /// Average time in milliseconds to complete a write including the time spent in queue.
+    pub write_await: f64,
+    /// Average time in milliseconds to complete a discard including the time spent in queue.
+    pub discard_await: f64,
+    /// Average time in milliseconds to complete a flush including the time spent in queue.
+    pub flush_await: f64,
+    /// Average size of a read request in bytes.
+    pub read_request_size: f64,
+    /// Average size of a write request in bytes.
+    pub write_request_size: f64,
+    /// Average number of requests queued or in flight.
+    pub queue_size: f64,
+    /// Percentage of time the device had at least one request in flight. Devices serving
+    /// requests in parallel, like SSDs and RAID arrays, can be far from saturated at 100.
+    pub utilization: f64,
+}
+
+#[derive(Clone, Debug, Default)]
+/// Samples I/O activity of all block devices and partitions reading /proc/diskstats once per
+/// sample
+pub struct DiskSampler {
+    prev: HashMap<String, DiskStats>,
+    at: Option<Instant>,
+}
+
+impl DiskSampler {
+    /// Creates a new sampler taking the initial reading of /proc/diskstats
+    pub fn new() -> Result<DiskSampler> {
+        let mut sampler = DiskSampler::default();
+        sampler.sample()?;
+        Ok(sampler)
+    }
+
+    /// Returns rates of all devices seen both in this and the previous sample sorted by name
+    pub fn sample(&mut self) -> Result<Vec<DiskRates>> {
+        let stats = disks_stats()?;
+        Ok(self.update(stats, Instant::now()))
+    }
+
+    fn update(&mut self, stats: Vec<DiskStats>, now: Instant) -> Vec<DiskRates> {
+        let elapsed = self.at.map(|at| now.saturating_duration_since(at));
+        let mut rates = Vec::new();
+
+        if let Some(elapsed) = elapsed {
+            for cur in &stats {
+                if let Some(prev) = self.prev.get(&cur.name) {
+                    rates.push(disk_rates(prev, cur, elapsed));
+                }
+            }
+        }
+
+        self.prev = stats.into_iter().map(|s| (s.name.clone(), s)).collect();
+        self.at = Some(now);
+        rates.sort_by(|a, b| a.name.cmp(&b.name));
+        rates
+    }
+}
+
+fn disk_rates(prev: &DiskStats, cur: &DiskStats, elapsed: Duration) -> DiskRates {
+    if elapsed == Duration::from_secs(0) {
+        return DiskRates {
+            name: cur.name.clone(),
+            ..Default::default()
+        };
+    }
+    let secs = elapsed.as_secs_f64();
+    let delta = |prev: u64, cur: u64| counter_delta(prev, cur) as f64;
+    // times are printed as unsigned int
+    let ticks = |prev: u64, cur: u64| counter_delta_32(prev, cur) as f64;
+    // returns 0 instead of NaN when there were no requests
+    let ratio = |a: f64, b: f64| if b > 0. { a / b } else { 0. };
+
+    let reads = delta(prev.reads, cur.reads);
+    let writes = delta(prev.writes, cur.writes);
+    let discards = delta(prev.discards, cur.discards);
+    let flushes = delta(prev.flushes, cur.flushes);
+    let read_bytes = delta(prev.sectors_read, cur.sectors_read) * SECTOR_SIZE;
+    let write_bytes = delta(prev.sectors_written, cur.sectors_written) * SECTOR_SIZE;
+    let millis = secs * 1000.;
+
+    DiskRates {
+        name: cur.name.clone(),
+        reads: reads / secs,
+        writes: writes / secs,
+        discards: discards / secs,
+        flushes: flushes / secs,
+        read_bytes: read_bytes / secs,
+        write_bytes: write_bytes / secs,
+        discard_bytes: delta(prev.sectors_discarded, cur.sectors_discarded) * SECTOR_SIZE / secs,
+        reads_merged: delta(prev.reads_merged, cur.reads_merged) / secs,
+        writes_merged: delta(prev.writes_merged, cur.writes_merged) / secs,
+        read_await: ratio(ticks(prev.read_ticks, cur.read_ticks), reads),
+        write_await: ratio(ticks(prev.write_ticks, cur.write_ticks), writes),
+        discard_await: ratio(ticks(prev.discard_ticks, cur.discard_ticks), discards),
+        flush_await: ratio(ticks(prev.flush_ticks, cur.flush_ticks), flushes),
+        read_request_size: ratio(read_bytes, reads),
+        write_request_size: ratio(write_bytes, writes),
+        queue_size: ticks(prev.time_in_queue, cur.time_in_queue) / millis,
+        utilization: (ticks(prev.io_ticks, cur.io_ticks) / millis * 100.).min(100.),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_disk_rates() {
+        let mut sampler = DiskSampler::default();
+        let start = Instant::now();
+        let sda = DiskStats {
+            name: "sda".to_string(),
+            reads: 1000,
+            sectors_read: 80_000,
+            read_ticks: 5000,
+            writes: 500,
+            writes_merged: 20,
+            sectors_written: 16_000,
+            write_ticks: 2000,
+            io_ticks: 10_000,
+            time_in_queue: 7000,
+            ..Default::default()
+        };
+
+        assert!(sampler
+            .update(vec![sda.clone(), DiskStats::default()], start)
+            .is_empty());
+
+        let rates = sampler.update(
+            vec![
+                DiskStats {
+                    reads: 1200,
+                    sectors_read: 96_000,
+                    read_ticks: 5800,
+                    writes: 600,
+                    writes_merged: 60,
+                    sectors_written: 24_000,
+                    write_ticks: 2600,
+                    io_ticks: 11_000,
+                    time_in_queue: 8400,
+                    ..sda.clone()
+                },
+                DiskStats {
+                    name: "sdb".to_string(),
+                    ..Default::default()
+                },
+            ],
+            start + Duration::from_secs(2),
+        );
+        assert_eq!(
+            rates,
+            vec![DiskRates {
+                name: "sda".to_string(),
+                reads: 100.,
+                writes: 50.,
+                read_bytes: 4_096_000.,
+                write_bytes: 2_048_000.,
+                writes_merged: 20.,
+                read_await: 4.,
+                write_await: 6.,
+                read_request_size: 40960.,
+                write_request_size: 40960.,
+                queue_size: 0.7,
+                utilization: 50.,
+                ..Default::default()
+            }]
+        );
+
+        // idle device
+        let rates = sampler.update(vec![sampler.prev["sda"].clone()], start + Duration::from_secs(3));
+        assert_eq!(
+            rates,
+            vec![DiskRates {
+                name: "sda".to_string(),
+                ..Default::default()
+            }]
+        );
+    }
+}
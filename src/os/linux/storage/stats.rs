@@ -0,0 +1,173 @@
+use crate::linux::SysFs;
+use crate::{util::next, Error, Result};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::str::SplitAsciiWhitespace;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// I/O counters of a block device or partition since boot parsed from /proc/diskstats.
+///
+/// Sectors are always 512 bytes and times are in milliseconds. Discard counters are reported
+/// since linux 4.18 and flush counters since linux 5.5, on older kernels they are 0.
+pub struct DiskStats {
+    pub major: u32,
+    pub minor: u32,
+    pub name: String,
+    /// Reads completed successfully.
+    pub reads: u64,
+    /// Adjacent reads merged into a single request.
+    pub reads_merged: u64,
+    pub sectors_read: u64,
+    /// Time spent on all reads.
+    pub read_ticks: u64,
+    pub writes: u64,
+    pub writes_merged: u64,
+    pub sectors_written: u64,
+    pub write_ticks: u64,
+    /// Requests issued to the device driver but not yet completed.
+    pub in_flight: u64,
+    /// Time the device had at least one request in flight.
+    pub io_ticks: u64,
+    /// Time spent on all requests weighted by the number of requests in flight.
+    pub time_in_queue: u64,
+    pub discards: u64,
+    pub discards_merged: u64,
+    pub sectors_discarded: u64,
+    pub discard_ticks: u64,
+    pub flushes: u64,
+    pub flush_ticks: u64,
+}
+
+impl DiskStats {
+    pub(crate) fn from_diskstats_line(line: &str) -> Result<DiskStats> {
+        let mut elems = line.split_ascii_whitespace();
+
+        macro_rules! _next {
+            ($t:tt) => {
+                next::<$t, SplitAsciiWhitespace>(&mut elems, &line)?
+            };
+        }
+
+        let mut stats = DiskStats {
+            major: _next!(u32),
+            minor: _next!(u32),
+            name: elems
+                .next()
+                .ok_or_else(|| Error::InvalidInputError(line.to_string(), "missing device name".to_string()))?
+                .to_string(),
+            reads: _next!(u64),
+            reads_merged: _next!(u64),
+            sectors_read: _next!(u64),
+            read_ticks: _next!(u64),
+            writes: _next!(u64),
+            writes_merged: _next!(u64),
+            sectors_written: _next!(u64),
+            write_ticks: _next!(u64),
+            in_flight: _next!(u64),
+            io_ticks: _next!(u64),
+            time_in_queue: _next!(u64),
+            ..Default::default()
+        };
+
+        // fields added by newer kernels
+        if elems.clone().next().is_some() {
+            stats.discards = _next!(u64);
+            stats.discards_merged = _next!(u64);
+            stats.sectors_discarded = _next!(u64);
+            stats.discard_ticks = _next!(u64);
+        }
+        if elems.clone().next().is_some() {
+            stats.flushes = _next!(u64);
+            stats.flush_ticks = _next!(u64);
+        }
+
+        Ok(stats)
+    }
+
+    pub(crate) fn from_diskstats(diskstats: &str) -> Result<Vec<DiskStats>> {
+        diskstats
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(DiskStats::from_diskstats_line)
+            .collect()
+    }
+}
+
+/// Returns I/O counters of all block devices and partitions parsed from /proc/diskstats
+pub fn disks_stats() -> Result<Vec<DiskStats>> {
+    DiskStats::from_diskstats(&SysFs::Proc.join("diskstats").read()?)
+}
+
+/// Returns I/O counters of device `name` parsed from /proc/diskstats
+pub fn disk_stats(name: &str) -> Result<DiskStats> {
+    disks_stats()?
+        .into_iter()
+        .find(|stats| stats.name == name)
+        .ok_or_else(|| Error::InvalidInputError(name.to_string(), "no such device in /proc/diskstats".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linux::mocks::DISKSTATS;
+
+    #[test]
+    fn parses_diskstats() {
+        let stats = DiskStats::from_diskstats(DISKSTATS).unwrap();
+        assert_eq!(
+            stats.iter().map(|s| s.name.as_str()).collect::<Vec<_>>(),
+            vec!["nvme0n1", "nvme0n1p1", "nvme0n1p2", "sda", "sdb", "dm-0"]
+        );
+        assert_eq!(
+            stats[0],
+            DiskStats {
+                major: 259,
+                minor: 0,
+                name: "nvme0n1".to_string(),
+                reads: 1_214_829,
+                reads_merged: 308_123,
+                sectors_read: 81_920_154,
+                read_ticks: 402_811,
+                writes: 4_938_121,
+                writes_merged: 1_920_342,
+                sectors_written: 290_117_320,
+                write_ticks: 5_120_374,
+                in_flight: 0,
+                io_ticks: 2_841_230,
+                time_in_queue: 5_611_992,
+                discards: 48120,
+                discards_merged: 0,
+                sectors_discarded: 98_240_384,
+                discard_ticks: 1203,
+                flushes: 418_233,
+                flush_ticks: 87111,
+            }
+        );
+
+        // linux 4.18 without flush counters
+        assert_eq!(stats[3].in_flight, 2);
+        assert_eq!(stats[3].discards, 0);
+        assert_eq!(stats[5].discard_ticks, 1211);
+        assert_eq!(stats[5].flushes, 0);
+
+        // before linux 4.18
+        assert_eq!(
+            stats[4],
+            DiskStats {
+                major: 8,
+                minor: 16,
+                name: "sdb".to_string(),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn fails_on_invalid_line() {
+        assert!(DiskStats::from_diskstats_line("8 0 sda 1 2 3").is_err());
+        assert!(DiskStats::from_diskstats_line("8 0").is_err());
+        assert!(DiskStats::from_diskstats_line("8 0 sda 1 2 3 4 5 6 7 8 9 10 11 12").is_err());
+    }
+}
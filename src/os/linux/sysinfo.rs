@@ -74,7 +74,7 @@ impl SysInfo {
 
     /// Returns the total amount of unused high memory size in Bytes.
     pub fn memory_high_free(&self) -> u64 {
-        self.scale_mem(self.0.totalhigh)
+        self.scale_mem(self.0.freehigh)
     }
 
     fn scale_mem(&self, units: u64) -> u64 {
@@ -3,12 +3,83 @@
 use crate::{Error, Result};
 
 use std::{
+    cell::RefCell,
     fmt::Display,
-    fs,
+    fs, io,
     path::{Path, PathBuf},
     str::FromStr,
+    sync::RwLock,
 };
 
+/// Root directories of procfs, sysfs and devfs that all linux readers go through.
+///
+/// By default these are `/proc`, `/sys` and `/dev`. Custom roots are useful when
+/// the host filesystems are bind-mounted into a container (for example at `/host/proc`)
+/// or when reading captured fixture trees.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SysRoots {
+    pub proc: PathBuf,
+    pub sys: PathBuf,
+    pub dev: PathBuf,
+}
+
+impl Default for SysRoots {
+    fn default() -> Self {
+        Self {
+            proc: PathBuf::from("/proc"),
+            sys: PathBuf::from("/sys"),
+            dev: PathBuf::from("/dev"),
+        }
+    }
+}
+
+impl SysRoots {
+    pub fn new<P: Into<PathBuf>, S: Into<PathBuf>, D: Into<PathBuf>>(proc: P, sys: S, dev: D) -> Self {
+        Self {
+            proc: proc.into(),
+            sys: sys.into(),
+            dev: dev.into(),
+        }
+    }
+}
+
+static ROOTS: RwLock<Option<SysRoots>> = RwLock::new(None);
+
+thread_local! {
+    static SCOPED_ROOTS: RefCell<Option<SysRoots>> = const { RefCell::new(None) };
+}
+
+/// Sets the roots used by all module-level functions of the linux api.
+pub fn set_roots(roots: SysRoots) {
+    *ROOTS.write().unwrap_or_else(|e| e.into_inner()) = Some(roots);
+}
+
+/// Returns the roots currently used by readers on this thread.
+pub fn roots() -> SysRoots {
+    if let Some(roots) = SCOPED_ROOTS.with(|r| r.borrow().clone()) {
+        return roots;
+    }
+    ROOTS
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .unwrap_or_default()
+}
+
+/// Runs `f` with `roots` overriding the module-level roots on the current thread.
+pub(crate) fn with_roots<T, F: FnOnce() -> T>(roots: &SysRoots, f: F) -> T {
+    struct Restore(Option<SysRoots>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let prev = self.0.take();
+            SCOPED_ROOTS.with(|r| *r.borrow_mut() = prev);
+        }
+    }
+
+    let _restore = Restore(SCOPED_ROOTS.with(|r| r.borrow_mut().replace(roots.clone())));
+    f()
+}
+
 /// SysPath is an abstraction around procfs and sysfs. Allows for easy reading and parsing
 /// of values in system paths.
 #[derive(Clone, Debug)]
@@ -19,20 +90,9 @@ pub(crate) enum SysFs {
     Custom(PathBuf),
 }
 
-impl AsRef<str> for SysFs {
-    fn as_ref(&self) -> &str {
-        match &self {
-            SysFs::Proc => "/proc",
-            SysFs::Sys => "/sys",
-            SysFs::Dev => "/dev",
-            SysFs::Custom(s) => s.to_str().unwrap_or_default(),
-        }
-    }
-}
-
 impl From<SysFs> for SysPath {
     fn from(path: SysFs) -> Self {
-        SysPath(PathBuf::from(path.as_ref()))
+        SysPath(path.into_pathbuf())
     }
 }
 
@@ -43,6 +103,16 @@ impl From<SysPath> for PathBuf {
 }
 
 impl SysFs {
+    /// Resolves this filesystem to its root directory using currently set roots
+    pub(crate) fn into_pathbuf(self) -> PathBuf {
+        match self {
+            SysFs::Proc => roots().proc,
+            SysFs::Sys => roots().sys,
+            SysFs::Dev => roots().dev,
+            SysFs::Custom(p) => p,
+        }
+    }
+
     pub(crate) fn join<P: AsRef<Path>>(self, path: P) -> SysPath {
         self.into_syspath().join(path)
     }
@@ -76,28 +146,49 @@ impl SysPath {
         PathBuf::from(self)
     }
 
-    /// Reads path to a string returning FileReadError on error
+    /// Reads path to a string returning FileReadError or PermissionDenied on error
     pub(crate) fn read(&self) -> Result<String> {
         let path = self.as_path();
-        fs::read_to_string(path).map_err(|e| Error::FileReadError(path.to_string_lossy().to_string(), e.to_string()))
+        fs::read_to_string(path).map_err(|e| file_error(path, e))
+    }
+
+    /// Reads path to bytes returning FileReadError or PermissionDenied on error
+    pub(crate) fn read_bytes(&self) -> Result<Vec<u8>> {
+        let path = self.as_path();
+        fs::read(path).map_err(|e| file_error(path, e))
     }
 
-    /// Reads path and parses it as T otherwise returns FileReadError or InvalidInputError on error
+    /// Reads path and parses it as T otherwise returns FileReadError, PermissionDenied or
+    /// InvalidInputError on error
     pub(crate) fn read_as<T: FromStr>(&self) -> Result<T>
     where
         <T as FromStr>::Err: Display,
     {
         let path = self.as_path();
-        let data = fs::read_to_string(path)
-            .map_err(|e| Error::FileReadError(path.to_string_lossy().to_string(), e.to_string()))?;
+        let data = fs::read_to_string(path).map_err(|e| file_error(path, e))?;
 
         T::from_str(data.trim()).map_err(|e| Error::InvalidInputError(data, e.to_string()))
     }
 
+    /// Reads the target of a symbolic link at this path
+    pub(crate) fn read_link(&self) -> Result<PathBuf> {
+        let path = self.as_path();
+        fs::read_link(path).map_err(|e| file_error(path, e))
+    }
+
     /// Returns iterator over entries of this path
     pub(crate) fn read_dir(&self) -> Result<fs::ReadDir> {
         let path = self.as_path();
-        fs::read_dir(path).map_err(|e| Error::FileReadError(path.to_string_lossy().to_string(), e.to_string()))
+        fs::read_dir(path).map_err(|e| file_error(path, e))
+    }
+}
+
+/// Maps an io error that occured while accessing `path` to PermissionDenied or FileReadError
+pub(crate) fn file_error(path: &Path, e: io::Error) -> Error {
+    let path = path.to_string_lossy().to_string();
+    match e.kind() {
+        io::ErrorKind::PermissionDenied => Error::PermissionDenied(path),
+        _ => Error::FileReadError(path, e.to_string()),
     }
 }
 
@@ -120,4 +211,31 @@ mod tests {
         path = SysPath(PathBuf::from("/home/user/"));
         assert_eq!(path, SysFs::Custom(PathBuf::from("/home")).join("user"));
     }
+
+    #[test]
+    fn joins_paths_with_custom_roots() {
+        let roots = SysRoots::new("/host/proc", "/host/sys", "/host/dev");
+        with_roots(&roots, || {
+            assert_eq!(
+                SysPath(PathBuf::from("/host/proc/12/cpuset")),
+                SysFs::Proc.join("12").join("cpuset")
+            );
+            assert_eq!(SysPath(PathBuf::from("/host/sys/block")), SysFs::Sys.join("block"));
+            assert_eq!(SysPath(PathBuf::from("/host/dev/sda")), SysFs::Dev.join("sda"));
+        });
+        assert_eq!(SysPath(PathBuf::from("/proc/12")), SysFs::Proc.join("12"));
+    }
+
+    #[test]
+    fn maps_permission_denied() {
+        let path = Path::new("/proc/1/io");
+        assert!(matches!(
+            file_error(path, io::Error::from(io::ErrorKind::PermissionDenied)),
+            Error::PermissionDenied(p) if p == "/proc/1/io"
+        ));
+        assert!(matches!(
+            file_error(path, io::Error::from(io::ErrorKind::NotFound)),
+            Error::FileReadError(_, _)
+        ));
+    }
 }
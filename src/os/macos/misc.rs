@@ -1,6 +1,7 @@
 use crate::macos::system::*;
-use crate::{Error, Result};
+use crate::{os::net::Interfaces, Error, Result};
 
+use nix::errno::Errno;
 use std::time::{SystemTime, UNIX_EPOCH};
 use sysctl::CtlValue;
 
@@ -34,6 +35,18 @@ pub fn uptime() -> Result<u64> {
     Ok(now - boot)
 }
 
+pub fn load_average() -> Result<(f64, f64, f64)> {
+    let mut loads = [0f64; 3];
+    let res = unsafe { libc::getloadavg(loads.as_mut_ptr(), 3) };
+    Errno::result(res)
+        .map(|_| (loads[0], loads[1], loads[2]))
+        .map_err(Error::from)
+}
+
+pub fn interfaces() -> Result<Interfaces> {
+    Err(Error::UnsupportedError("network interfaces".to_string()))
+}
+
 pub fn domain_name() -> Result<String> {
     match sysctl(SYSCTL_DOMAINNAME)? {
         CtlValue::String(cpu) => Ok(cpu),
@@ -8,10 +8,10 @@ mod system;
 pub use crate::os::unix::arch;
 pub use cpu::{cpu, cpu_clock, cpu_cores, logical_cores};
 pub use mem::{memory_free, memory_total, swap_free, swap_total};
-pub use misc::{domain_name, hostname, model, uptime};
+pub use misc::{domain_name, hostname, interfaces, load_average, model, uptime};
 pub use os_impl_ext::OsImplExt;
 
-use crate::os::OsImpl;
+use crate::os::{net::Interfaces, OsImpl};
 use crate::Result;
 
 #[derive(Default)]
@@ -70,4 +70,12 @@ impl OsImpl for MacOS {
     fn swap_free(&self) -> Result<usize> {
         swap_free()
     }
+
+    fn load_average(&self) -> Result<(f64, f64, f64)> {
+        load_average()
+    }
+
+    fn interfaces(&self) -> Result<Interfaces> {
+        interfaces()
+    }
 }
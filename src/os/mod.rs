@@ -14,5 +14,6 @@ pub(crate) use macos::OsImplExt;
 #[cfg(target_os = "windows")]
 pub(crate) use windows::OsImplExt;
 
+pub mod net;
 pub(crate) mod os_impl;
 pub(crate) use os_impl::OsImpl;
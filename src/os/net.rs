@@ -0,0 +1,105 @@
+//! Network types shared by all platforms
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+
+pub type Interfaces = Vec<Interface>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Kind of a network interface
+pub enum InterfaceType {
+    Loopback,
+    Ether,
+    Bridge,
+    Veth,
+    Tun,
+    /// Any other link type with its `ARPHRD_*` number read from /sys/class/net/[iface]/type
+    Other(u16),
+}
+
+impl Default for InterfaceType {
+    fn default() -> Self {
+        InterfaceType::Other(0)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// RFC 2863 operational state of an interface
+pub enum OperState {
+    #[default]
+    Unknown,
+    NotPresent,
+    Down,
+    LowerLayerDown,
+    Testing,
+    Dormant,
+    Up,
+}
+
+impl From<&str> for OperState {
+    fn from(s: &str) -> Self {
+        match s.trim() {
+            "notpresent" => OperState::NotPresent,
+            "down" => OperState::Down,
+            "lowerlayerdown" => OperState::LowerLayerDown,
+            "testing" => OperState::Testing,
+            "dormant" => OperState::Dormant,
+            "up" => OperState::Up,
+            _ => OperState::Unknown,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub enum Duplex {
+    Full,
+    Half,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents an address assigned to an interface
+pub struct InterfaceAddress {
+    pub address: IpAddr,
+    pub netmask: Option<IpAddr>,
+    pub broadcast: Option<IpAddr>,
+}
+
+impl InterfaceAddress {
+    /// Returns the prefix length of this address computed from the netmask
+    pub fn prefix_len(&self) -> Option<u8> {
+        self.netmask.map(|mask| match mask {
+            IpAddr::V4(mask) => u32::from(mask).count_ones() as u8,
+            IpAddr::V6(mask) => u128::from(mask).count_ones() as u8,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// Represents a network interface
+pub struct Interface {
+    pub name: String,
+    pub index: u32,
+    pub kind: InterfaceType,
+    /// Hardware address or None if the interface doesn't have one, for example a tun device.
+    pub mac: Option<String>,
+    pub mtu: u32,
+    pub operstate: OperState,
+    /// Whether the physical link is up or None if it can't be determined because the interface
+    /// is administratively down.
+    pub carrier: Option<bool>,
+    /// Link speed in Mbit/s or None if it's unknown or the interface doesn't report it.
+    pub speed: Option<u32>,
+    pub duplex: Option<Duplex>,
+    /// Interface flags, `IFF_*` constants from linux/if.h. Use `operstate` to determine whether
+    /// the link is operational as sysfs doesn't report `IFF_RUNNING`.
+    pub flags: u32,
+    /// Addresses assigned to this interface. On linux these are returned by `getifaddrs` for the
+    /// network namespace of the current process and left empty when sysfs has a custom root.
+    pub addresses: Vec<InterfaceAddress>,
+}
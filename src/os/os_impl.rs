@@ -1,4 +1,4 @@
-use crate::Result;
+use crate::{os::net::Interfaces, Result};
 
 /// Common api
 pub(crate) trait OsImpl {
@@ -14,4 +14,6 @@ pub(crate) trait OsImpl {
     fn memory_free(&self) -> Result<usize>;
     fn swap_total(&self) -> Result<usize>;
     fn swap_free(&self) -> Result<usize>;
+    fn load_average(&self) -> Result<(f64, f64, f64)>;
+    fn interfaces(&self) -> Result<Interfaces>;
 }
@@ -3,7 +3,7 @@ mod internal;
 mod os_impl_ext;
 mod public;
 
-use super::OsImpl;
+use super::{net::Interfaces, OsImpl};
 use crate::{Error, Result};
 use std::{
     ffi::OsString,
@@ -104,4 +104,12 @@ impl OsImpl for Windows {
     fn swap_free(&self) -> Result<usize> {
         swap_free()
     }
+
+    fn load_average(&self) -> Result<(f64, f64, f64)> {
+        load_average()
+    }
+
+    fn interfaces(&self) -> Result<Interfaces> {
+        interfaces()
+    }
 }
@@ -74,24 +74,28 @@ pub fn swap_free() -> Result<usize> {
     Ok(memory_status()?.ullAvailVirtual as usize)
 }
 
+pub fn load_average() -> Result<(f64, f64, f64)> {
+    Err(Error::UnsupportedError("load average".to_string()))
+}
+
 pub fn default_iface() -> Result<String> {
-    Ok("".to_string())
+    Err(Error::UnsupportedError("default interface".to_string()))
 }
 
-pub fn ipv4(iface: &str) -> Result<String> {
-    Ok("".to_string())
+pub fn ipv4(_iface: &str) -> Result<String> {
+    Err(Error::UnsupportedError("interface ipv4 address".to_string()))
 }
 
 pub fn ipv6(_iface: &str) -> Result<String> {
-    Ok("".to_string())
+    Err(Error::UnsupportedError("interface ipv6 address".to_string()))
 }
 
-pub fn mac(iface: &str) -> Result<String> {
-    Ok("".to_string())
+pub fn mac(_iface: &str) -> Result<String> {
+    Err(Error::UnsupportedError("interface mac address".to_string()))
 }
 
-pub fn interfaces() -> Result<Vec<String>> {
-    Ok(vec![])
+pub fn interfaces() -> Result<Interfaces> {
+    Err(Error::UnsupportedError("network interfaces".to_string()))
 }
 
 pub fn domainname() -> Result<String> {
@@ -1,6 +1,6 @@
 #![cfg_attr(target_os = "macos", allow(dead_code))]
 use super::Error;
-use std::any::type_name;
+use std::{any::type_name, convert::TryFrom};
 
 pub fn trim_parse_map<T>(inp: &str) -> Result<T, Error>
 where
@@ -39,3 +39,41 @@ where
     }
     iter
 }
+
+/// Returns the increase of a counter between two readings.
+///
+/// Kernel counters wrap around at the width of their type, which for `unsigned long` depends on
+/// the architecture of the kernel and for counters kept by some network drivers is 32 bits
+/// regardless. Unless the width is known a decrease can't be told apart from a reset, for example
+/// of a recreated device, so it's treated as one and the current value is returned. Counters known
+/// to be 32 bits wide should use `counter_delta_32` instead.
+pub fn counter_delta(prev: u64, cur: u64) -> u64 {
+    cur.checked_sub(prev).unwrap_or(cur)
+}
+
+/// Returns the increase of a 32 bit counter between two readings treating a decrease as
+/// a wraparound. Falls back to `counter_delta` for readings that don't fit in 32 bits.
+pub fn counter_delta_32(prev: u64, cur: u64) -> u64 {
+    match (u32::try_from(prev), u32::try_from(cur)) {
+        (Ok(prev), Ok(cur)) => u64::from(cur.wrapping_sub(prev)),
+        _ => counter_delta(prev, cur),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_counter_delta() {
+        assert_eq!(counter_delta(100, 300), 200);
+        // reset
+        assert_eq!(counter_delta(1000, 10), 10);
+        assert_eq!(counter_delta(u64::from(u32::MAX) - 99, 100), 100);
+        assert_eq!(counter_delta(u64::from(u32::MAX) + 1000, 500), 500);
+
+        assert_eq!(counter_delta_32(100, 300), 200);
+        assert_eq!(counter_delta_32(u64::from(u32::MAX) - 99, 100), 200);
+        assert_eq!(counter_delta_32(u64::from(u32::MAX) + 1000, 500), 500);
+    }
+}